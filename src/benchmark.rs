@@ -0,0 +1,313 @@
+use crate::runner::run_guarded;
+use crate::solution::{Part, Solver};
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const HISTORY_HEADER: &str = "timestamp,revision,day,phase,runs,min_ns,median_ns,mean_ns,max_ns";
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve(part) => f.pad(&format!("part {}", part)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct TimingStatistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl TimingStatistics {
+    pub fn new(durations: &[Duration]) -> Option<TimingStatistics> {
+        let mut sorted_durations = durations.to_vec();
+
+        sorted_durations.sort();
+
+        let number_of_durations = sorted_durations.len();
+
+        let min = *sorted_durations.first()?;
+        let max = *sorted_durations.last()?;
+
+        let median = if number_of_durations.is_multiple_of(2) {
+            (sorted_durations[number_of_durations / 2 - 1]
+                + sorted_durations[number_of_durations / 2])
+                / 2
+        } else {
+            sorted_durations[number_of_durations / 2]
+        };
+
+        let mean = sorted_durations.iter().sum::<Duration>() / number_of_durations as u32;
+
+        Some(TimingStatistics {
+            min,
+            median,
+            mean,
+            max,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BenchmarkReport {
+    pub day: u32,
+    pub runs: usize,
+    pub phases: Vec<(Phase, TimingStatistics)>,
+}
+
+impl BenchmarkReport {
+    pub fn append_to_history(&self, file_name: &str, revision: &str) -> Result<(), String> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_name)
+            .map_err(|error| format!("Could not open {}: {}", file_name, error))?;
+
+        let is_new_file = file
+            .metadata()
+            .map(|metadata| metadata.len() == 0)
+            .map_err(|error| format!("Could not read {}: {}", file_name, error))?;
+
+        if is_new_file {
+            writeln!(file, "{}", HISTORY_HEADER)
+                .map_err(|error| format!("Could not write {}: {}", file_name, error))?;
+        }
+
+        for (phase, statistics) in self.phases.iter() {
+            writeln!(
+                file,
+                "{},{},{},{},{},{},{},{},{}",
+                timestamp,
+                revision,
+                self.day,
+                phase,
+                self.runs,
+                statistics.min.as_nanos(),
+                statistics.median.as_nanos(),
+                statistics.mean.as_nanos(),
+                statistics.max.as_nanos()
+            )
+            .map_err(|error| format!("Could not write {}: {}", file_name, error))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Day {} ({} runs)", self.day, self.runs)?;
+        writeln!(
+            f,
+            "{:<8}  {:>12}  {:>12}  {:>12}  {:>12}",
+            "Phase", "Min", "Median", "Mean", "Max"
+        )?;
+
+        for (phase, statistics) in self.phases.iter() {
+            writeln!(
+                f,
+                "{:<8}  {:>12}  {:>12}  {:>12}  {:>12}",
+                phase,
+                format!("{:.3?}", statistics.min),
+                format!("{:.3?}", statistics.median),
+                format!("{:.3?}", statistics.mean),
+                format!("{:.3?}", statistics.max)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn benchmark_day(
+    day: u32,
+    solver: &dyn Solver,
+    input: &[String],
+    parts: &[Part],
+    runs: usize,
+) -> Result<BenchmarkReport, String> {
+    if runs == 0 {
+        return Err(String::from("Need at least one run to benchmark!"));
+    }
+
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut part_times = vec![Vec::with_capacity(runs); parts.len()];
+
+    for _ in 0..runs {
+        let (parsed_input, parse_time) =
+            run_guarded(|| solver.parse_input(input).map_err(|error| error.to_string()))
+                .map_err(|error| error.to_string())?;
+
+        parse_times.push(parse_time);
+
+        for (part, times) in parts.iter().zip(part_times.iter_mut()) {
+            let (_, part_time) = run_guarded(|| solver.solve(&parsed_input, *part))
+                .map_err(|error| error.to_string())?;
+
+            times.push(part_time);
+        }
+    }
+
+    let mut phases = vec![(Phase::Parse, parse_times)];
+
+    phases.extend(parts.iter().map(|&part| Phase::Solve(part)).zip(part_times));
+
+    Ok(BenchmarkReport {
+        day,
+        runs,
+        phases: phases
+            .into_iter()
+            .filter_map(|(phase, times)| Some((phase, TimingStatistics::new(&times)?)))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Answer, Solution};
+    use crate::util::parse_error::ParseError;
+
+    struct LineCounter;
+
+    impl Solution for LineCounter {
+        type Input = usize;
+
+        fn parse(&self, input: &[String]) -> Result<usize, ParseError> {
+            Ok(input.len())
+        }
+
+        fn part_one(&self, input: &usize) -> Result<Answer, String> {
+            Ok(Answer::from(*input))
+        }
+
+        fn part_two(&self, _input: &usize) -> Result<Answer, String> {
+            Err(String::from("oops"))
+        }
+    }
+
+    struct Panicker;
+
+    impl Solution for Panicker {
+        type Input = usize;
+
+        fn parse(&self, input: &[String]) -> Result<usize, ParseError> {
+            Ok(input.len())
+        }
+
+        fn part_one(&self, _input: &usize) -> Result<Answer, String> {
+            panic!("boom")
+        }
+
+        fn part_two(&self, _input: &usize) -> Result<Answer, String> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn test_timing_statistics_new() {
+        let durations: Vec<Duration> = [4, 1, 3, 8]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+
+        let expected = Some(TimingStatistics {
+            min: Duration::from_millis(1),
+            median: Duration::from_micros(3500),
+            mean: Duration::from_millis(4),
+            max: Duration::from_millis(8),
+        });
+
+        let result = TimingStatistics::new(&durations);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_timing_statistics_new_odd_and_empty() {
+        let durations: Vec<Duration> = [5, 1, 3]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+
+        let result = TimingStatistics::new(&durations).map(|statistics| statistics.median);
+
+        assert_eq!(result, Some(Duration::from_millis(3)));
+        assert_eq!(TimingStatistics::new(&[]), None);
+    }
+
+    #[test]
+    fn test_benchmark_day() {
+        let input = vec![String::from("a"), String::from("b")];
+
+        let report = benchmark_day(1, &LineCounter, &input, &[Part::One], 3).unwrap();
+
+        let expected = vec![Phase::Parse, Phase::Solve(Part::One)];
+
+        let result: Vec<Phase> = report.phases.iter().map(|(phase, _)| *phase).collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(report.runs, 3);
+    }
+
+    #[test]
+    fn test_append_to_history() {
+        let file_name = std::env::temp_dir().join(format!(
+            "adventofcode2021_history_{}.csv",
+            std::process::id()
+        ));
+        let file_name = file_name.to_str().unwrap();
+
+        let input = vec![String::from("a")];
+
+        let report = benchmark_day(1, &LineCounter, &input, &[Part::One], 1).unwrap();
+
+        report.append_to_history(file_name, "abc123").unwrap();
+        report.append_to_history(file_name, "abc123").unwrap();
+
+        let contents = std::fs::read_to_string(file_name).unwrap();
+        let _ = std::fs::remove_file(file_name);
+
+        let lines: Vec<&str> = contents.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], HISTORY_HEADER);
+        assert!(lines[1..].iter().all(|line| line.contains(",abc123,1,")));
+    }
+
+    #[test]
+    fn test_benchmark_day_errors() {
+        let input = vec![String::from("a")];
+
+        assert!(benchmark_day(1, &LineCounter, &input, &[Part::One], 0).is_err());
+        assert!(benchmark_day(1, &LineCounter, &input, &[Part::Two], 2).is_err());
+    }
+
+    #[test]
+    fn test_benchmark_day_panics() {
+        let input = vec![String::from("a")];
+
+        let result_one = benchmark_day(1, &Panicker, &input, &[Part::One], 2);
+        let result_two = benchmark_day(1, &Panicker, &input, &[Part::Two], 2);
+
+        assert_eq!(result_one, Err(String::from("boom")));
+        assert!(result_two.is_err());
+    }
+}
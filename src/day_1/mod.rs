@@ -1,13 +1,45 @@
-use crate::util::file_reader::to_string_vector;
-
 mod sonar_sweep_reader;
 
-pub fn run_day_1() {
-    let file_input = to_string_vector("inputs/day_1.txt").unwrap();
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use sonar_sweep_reader::{
+    get_depths, get_number_of_increases, get_number_of_three_sum_increases,
+};
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263",
+    part_one: Some("7"),
+    part_two: Some("5"),
+}];
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &[String]) -> Result<Vec<u32>, ParseError> {
+        sonar_sweep_reader::get_depths(input)
+    }
+
+    fn part_one(&self, depths: &Vec<u32>) -> Result<Answer, String> {
+        Ok(sonar_sweep_reader::get_number_of_increases(depths).into())
+    }
 
-    let day_1_part_1_result = sonar_sweep_reader::get_number_of_increases(&file_input);
-    let day_1_part_2_result = sonar_sweep_reader::get_number_of_three_sum_increases(&file_input);
+    fn part_two(&self, depths: &Vec<u32>) -> Result<Answer, String> {
+        Ok(sonar_sweep_reader::get_number_of_three_sum_increases(depths).into())
+    }
 
-    println!("Day 1 Part 1: {}", day_1_part_1_result);
-    println!("Day 1 Part 2: {}", day_1_part_2_result);
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
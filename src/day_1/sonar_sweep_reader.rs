@@ -1,13 +1,15 @@
-pub fn get_number_of_increases(depth_data: &[String]) -> u32 {
+use crate::util::parse_error::{parse_lines_with, parse_number, ParseError};
+
+pub fn get_depths(input: &[String]) -> Result<Vec<u32>, ParseError> {
+    parse_lines_with(input, parse_number)
+}
+
+pub fn get_number_of_increases(depth_data: &[u32]) -> u32 {
     let mut result = 0;
 
     let mut previous_depth_option: Option<u32> = None;
 
-    for depth_str in depth_data {
-        let current_depth = depth_str
-            .parse::<u32>()
-            .expect(&format!("Cannot parse depth: {}", depth_str));
-
+    for &current_depth in depth_data {
         result += get_parity_optional_comparison(current_depth, previous_depth_option);
 
         previous_depth_option = Some(current_depth);
@@ -16,18 +18,14 @@ pub fn get_number_of_increases(depth_data: &[String]) -> u32 {
     result
 }
 
-pub fn get_number_of_three_sum_increases(depth_data: &[String]) -> u32 {
+pub fn get_number_of_three_sum_increases(depth_data: &[u32]) -> u32 {
     let mut result = 0;
 
     let mut sum_1 = 0;
     let mut sum_2 = 0;
     let mut sum_3 = 0;
 
-    for (index, depth_str) in depth_data.iter().enumerate() {
-        let current_depth = depth_str
-            .parse::<u32>()
-            .expect(&format!("Cannot parse depth: {}", depth_str));
-
+    for (index, &current_depth) in depth_data.iter().enumerate() {
         if index < 3 {
             sum_1 += current_depth;
         }
@@ -71,26 +69,36 @@ fn get_parity_comparison(current_number: u32, previous_number: u32) -> u32 {
 mod tests {
     use super::*;
 
-    const TEST_DATA: [&str; 10] = [
-        "199", "200", "208", "210", "200", "207", "240", "269", "260", "263",
-    ];
+    const TEST_DATA: [u32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
 
     #[test]
-    fn test_get_number_of_increases() {
-        let input: Vec<String> = TEST_DATA.iter().map(|data| data.to_string()).collect();
+    fn test_get_depths() {
+        let input: Vec<String> = ["199", "200", "2o8"]
+            .iter()
+            .map(|data| data.to_string())
+            .collect();
+
+        let result = get_depths(&input[..2]);
+
+        assert_eq!(result, Ok(vec![199, 200]));
+        assert_eq!(
+            get_depths(&input).unwrap_err().to_string(),
+            "3: expected a number, but got '2o8'"
+        );
+    }
 
+    #[test]
+    fn test_get_number_of_increases() {
         let expected = 7;
-        let result = get_number_of_increases(&input);
+        let result = get_number_of_increases(&TEST_DATA);
 
         assert_eq!(result, expected);
     }
 
     #[test]
     fn test_get_number_of_three_sum_increases() {
-        let input: Vec<String> = TEST_DATA.iter().map(|data| data.to_string()).collect();
-
         let expected = 5;
-        let result = get_number_of_three_sum_increases(&input);
+        let result = get_number_of_three_sum_increases(&TEST_DATA);
 
         assert_eq!(result, expected);
     }
@@ -1,16 +1,46 @@
 mod syntax_checker;
 
-use crate::util::file_reader::to_string_vector;
-use syntax_checker::SyntaxChecker;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use syntax_checker::SyntaxChecker;
 
-pub fn run_day_10() {
-    let file_input = to_string_vector("inputs/day_10.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]",
+    part_one: Some("26397"),
+    part_two: Some("288957"),
+}];
 
-    let checker = SyntaxChecker::new(&file_input);
+pub struct Day10;
 
-    let part_1_result = checker.get_total_error_score();
-    let part_2_result = checker.get_autocomplete_score();
+impl Solution for Day10 {
+    type Input = SyntaxChecker;
 
-    println!("Day 10 Part 1: {}", part_1_result);
-    println!("Day 10 Part 2: {}", part_2_result);
+    fn parse(&self, input: &[String]) -> Result<SyntaxChecker, ParseError> {
+        SyntaxChecker::try_from(input)
+    }
+
+    fn part_one(&self, syntax_checker: &SyntaxChecker) -> Result<Answer, String> {
+        syntax_checker.get_total_error_score().map(Answer::from)
+    }
+
+    fn part_two(&self, syntax_checker: &SyntaxChecker) -> Result<Answer, String> {
+        syntax_checker
+            .get_autocomplete_score()?
+            .map(Answer::from)
+            .ok_or_else(|| String::from("No incomplete lines!"))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
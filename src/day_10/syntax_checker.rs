@@ -1,45 +1,56 @@
+use crate::util::parse_error::{parse_lines, ParseError};
+use std::str::FromStr;
+
 struct Line {
     contents: String,
 }
 
-impl Line {
-    fn new(input: &str) -> Line {
-        Line {
-            contents: String::from(input),
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Line, ParseError> {
+        if let Some(column_index) = input.find(|chunk_element| !"()[]{}<>".contains(chunk_element))
+        {
+            return Err(
+                ParseError::new(input, "expected one of '()[]{}<>'").at_column(column_index + 1)
+            );
         }
+
+        Ok(Line {
+            contents: String::from(input),
+        })
     }
+}
+
+const CHUNK_PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
 
-    fn get_first_illegal_character(&self) -> Option<char> {
+impl Line {
+    fn get_first_illegal_character(&self) -> Result<Option<char>, String> {
         let mut stack: Vec<char> = Vec::new();
 
         for chunk_element in self.contents.chars() {
-            match chunk_element {
-                '(' | '[' | '{' | '<' => stack.push(chunk_element),
-                ')' => {
-                    if stack.pop().unwrap() != '(' {
-                        return Some(chunk_element);
-                    }
-                }
-                ']' => {
-                    if stack.pop().unwrap() != '[' {
-                        return Some(chunk_element);
-                    }
-                }
-                '}' => {
-                    if stack.pop().unwrap() != '{' {
-                        return Some(chunk_element);
-                    }
+            let opening = match Self::get_opening(chunk_element) {
+                Some(opening) => opening,
+                None => {
+                    stack.push(chunk_element);
+
+                    continue;
                 }
-                '>' => {
-                    if stack.pop().unwrap() != '<' {
-                        return Some(chunk_element);
-                    }
+            };
+
+            match stack.pop() {
+                Some(top) if top == opening => {}
+                Some(_) => return Ok(Some(chunk_element)),
+                None => {
+                    return Err(format!(
+                        "Unopened '{}' in line: {}",
+                        chunk_element, self.contents
+                    ))
                 }
-                _ => panic!("Unknown character: {}", chunk_element),
             }
         }
 
-        None
+        Ok(None)
     }
 
     // assumes no errors
@@ -47,30 +58,33 @@ impl Line {
         let mut stack: Vec<char> = Vec::new();
 
         for chunk_element in self.contents.chars() {
-            match chunk_element {
-                '(' | '[' | '{' | '<' => stack.push(chunk_element),
-                ')' | ']' | '}' | '>' => {
+            match Self::get_opening(chunk_element) {
+                Some(_) => {
                     stack.pop();
                 }
-                _ => panic!("Unknown character: {}", chunk_element),
+                None => stack.push(chunk_element),
             }
         }
 
         stack
             .iter()
             .rev()
-            .map(|chunk_element| Self::reverse_chunk_element(*chunk_element))
+            .filter_map(|&chunk_element| Self::get_closing(chunk_element))
             .collect()
     }
 
-    fn reverse_chunk_element(chunk_element: char) -> char {
-        match chunk_element {
-            '(' => ')',
-            '[' => ']',
-            '{' => '}',
-            '<' => '>',
-            _ => panic!("Unknown character: {}", chunk_element),
-        }
+    fn get_opening(chunk_element: char) -> Option<char> {
+        CHUNK_PAIRS
+            .iter()
+            .find(|(_, closing)| *closing == chunk_element)
+            .map(|(opening, _)| *opening)
+    }
+
+    fn get_closing(chunk_element: char) -> Option<char> {
+        CHUNK_PAIRS
+            .iter()
+            .find(|(opening, _)| *opening == chunk_element)
+            .map(|(_, closing)| *closing)
     }
 }
 
@@ -78,45 +92,49 @@ pub struct SyntaxChecker {
     lines: Vec<Line>,
 }
 
-impl SyntaxChecker {
-    pub fn new(input: &[String]) -> SyntaxChecker {
-        SyntaxChecker {
-            lines: input.iter().map(|s| Line::new(s)).collect(),
-        }
+impl TryFrom<&[String]> for SyntaxChecker {
+    type Error = ParseError;
+
+    fn try_from(input: &[String]) -> Result<SyntaxChecker, ParseError> {
+        Ok(SyntaxChecker {
+            lines: parse_lines(input)?,
+        })
     }
+}
 
-    pub fn get_total_error_score(&self) -> u64 {
+impl SyntaxChecker {
+    pub fn get_total_error_score(&self) -> Result<u64, String> {
         let mut result = 0;
 
         for line in &self.lines {
-            if let Some(error_char) = line.get_first_illegal_character() {
-                match error_char {
-                    ')' => result += 3,
-                    ']' => result += 57,
-                    '}' => result += 1197,
-                    '>' => result += 25137,
-                    _ => panic!("Unknown character: {}", error_char),
-                }
-            }
+            result += match line.get_first_illegal_character()? {
+                Some(')') => 3,
+                Some(']') => 57,
+                Some('}') => 1197,
+                Some('>') => 25137,
+                _ => 0,
+            };
         }
 
-        result
+        Ok(result)
     }
 
-    pub fn get_autocomplete_score(&self) -> u64 {
-        let mut results: Vec<u64> = self
-            .lines
-            .iter()
-            .filter(|line| line.get_first_illegal_character().is_none())
-            .map(|line| line.get_autocomplete_characters_in_order())
-            .map(|autocomplete_result| Self::score_autocomplete_result(&autocomplete_result))
-            .collect();
+    pub fn get_autocomplete_score(&self) -> Result<Option<u64>, String> {
+        let mut results: Vec<u64> = Vec::new();
+
+        for line in &self.lines {
+            if line.get_first_illegal_character()?.is_none() {
+                results.push(Self::score_autocomplete_result(
+                    &line.get_autocomplete_characters_in_order(),
+                ));
+            }
+        }
 
         let median_index = results.len() / 2;
 
         results.sort();
 
-        *results.get(median_index).expect("Could not get median!")
+        Ok(results.get(median_index).copied())
     }
 
     fn score_autocomplete_result(autocomplete_result: &[char]) -> u64 {
@@ -126,8 +144,7 @@ impl SyntaxChecker {
                 ')' => 1,
                 ']' => 2,
                 '}' => 3,
-                '>' => 4,
-                _ => panic!("Unknown character: {}", character),
+                _ => 4,
             })
             .fold(0, |acc, score| (acc * 5) + score)
     }
@@ -136,25 +153,27 @@ impl SyntaxChecker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_10::EXAMPLES;
+
+    #[test]
+    fn test_syntax_checker_try_from_error() {
+        let input: Vec<String> = vec![
+            String::from("[({(<(())[]>[[{[]{<()<>>"),
+            String::from("[(a)]"),
+        ];
 
-    const TEST_DATA: [&str; 10] = [
-        "[({(<(())[]>[[{[]{<()<>>",
-        "[(()[<>])]({[<{<<[]>>(",
-        "{([(<{}[<>[]}>{[]{[(<()>",
-        "(((({<>}<{<{<>}{[]{[]{}",
-        "[[<[([]))<([[{}[[()]]]",
-        "[{[{({}]{}}([{[{{{}}([]",
-        "{<[[]]>}<{[{[{[]{()[[[]",
-        "[<(<(<(<{}))><([]([]()",
-        "<{([([[(<>()){}]>(<<{{",
-        "<{([{{}}[<[[[<>{}]]]>[]]",
-    ];
+        let expected = "2:3: expected one of '()[]{}<>'";
+
+        let result = SyntaxChecker::try_from(&input[..]).map_err(|error| error.to_string());
+
+        assert_eq!(result.err().as_deref(), Some(expected));
+    }
 
     #[test]
     fn test_line_get_first_illegal_character_some() {
-        let test_line = Line::new(TEST_DATA[2]);
+        let test_line = "{([(<{}[<>[]}>{[]{[(<()>".parse::<Line>().unwrap();
 
-        let expected = Some('}');
+        let expected = Ok(Some('}'));
 
         let result = test_line.get_first_illegal_character();
 
@@ -163,9 +182,9 @@ mod tests {
 
     #[test]
     fn test_line_get_first_illegal_character_none() {
-        let test_line = Line::new(TEST_DATA[0]);
+        let test_line = "[({(<(())[]>[[{[]{<()<>>".parse::<Line>().unwrap();
 
-        let expected = None;
+        let expected = Ok(None);
 
         let result = test_line.get_first_illegal_character();
 
@@ -174,7 +193,7 @@ mod tests {
 
     #[test]
     fn test_line_get_autocomplete_characters_in_order() {
-        let test_line = Line::new(TEST_DATA[0]);
+        let test_line = "[({(<(())[]>[[{[]{<()<>>".parse::<Line>().unwrap();
 
         let expected = vec!['}', '}', ']', ']', ')', '}', ')', ']'];
 
@@ -185,11 +204,11 @@ mod tests {
 
     #[test]
     fn test_syntax_checker_get_total_error_score() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
-        let test_checker = SyntaxChecker::new(&input);
+        let test_checker = SyntaxChecker::try_from(&input[..]).unwrap();
 
-        let expected = 26397;
+        let expected = Ok(26397);
 
         let result = test_checker.get_total_error_score();
 
@@ -198,17 +217,28 @@ mod tests {
 
     #[test]
     fn test_syntax_checker_get_autocomplete_score() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
-        let test_checker = SyntaxChecker::new(&input);
+        let test_checker = SyntaxChecker::try_from(&input[..]).unwrap();
 
-        let expected = 288957;
+        let expected = Ok(Some(288957));
 
         let result = test_checker.get_autocomplete_score();
 
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_syntax_checker_unopened_and_no_incomplete_lines() {
+        let unopened = SyntaxChecker::try_from(&[String::from(")")][..]).unwrap();
+        let corrupted_only =
+            SyntaxChecker::try_from(&[String::from("{([(<{}[<>[]}>{[]{[(<()>")][..]).unwrap();
+
+        assert!(unopened.get_total_error_score().is_err());
+        assert!(unopened.get_autocomplete_score().is_err());
+        assert_eq!(corrupted_only.get_autocomplete_score(), Ok(None));
+    }
+
     #[test]
     fn test_syntax_checker_score_autocomplete_result() {
         let input: Vec<char> = "}}>}>))))".chars().collect();
@@ -1,36 +1,54 @@
 mod octopus_simulator;
 
-use crate::util::file_reader::to_string_vector;
-use octopus_simulator::OctopusFlashSimulator;
-
-pub fn run_day_11() {
-    let file_input = to_string_vector("inputs/day_11.txt").unwrap();
-
-    let mut simulator = OctopusFlashSimulator::new(&file_input);
-
-    let number_of_octopi = simulator.get_number_of_octopi();
-
-    let mut previous_number_flashed = 0;
-
-    let mut step = 1;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use octopus_simulator::OctopusFlashSimulator;
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526",
+    part_one: Some("1656"),
+    part_two: Some("195"),
+}];
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = OctopusFlashSimulator;
+
+    fn parse(&self, input: &[String]) -> Result<OctopusFlashSimulator, ParseError> {
+        OctopusFlashSimulator::try_from(input)
+    }
 
-    let mut part_1_result = 0;
-    let mut part_2_result = 0;
+    fn part_one(&self, simulator: &OctopusFlashSimulator) -> Result<Answer, String> {
+        let result: u32 = simulator.clone().take(100).sum();
 
-    while (previous_number_flashed != number_of_octopi) || (step < 100) {
-        previous_number_flashed = simulator.next().unwrap();
+        Ok(result.into())
+    }
 
-        if step <= 100 {
-            part_1_result += previous_number_flashed;
-        }
+    fn part_two(&self, simulator: &OctopusFlashSimulator) -> Result<Answer, String> {
+        let number_of_octopi = simulator.get_number_of_octopi();
 
-        if previous_number_flashed == number_of_octopi {
-            part_2_result = step;
-        }
+        let result = simulator
+            .clone()
+            .enumerate()
+            .find(|(_, number_flashed)| *number_flashed == number_of_octopi)
+            .map(|(step, _)| step + 1)
+            .ok_or_else(|| String::from("Octopi never flash all at once!"))?;
 
-        step += 1;
+        Ok(result.into())
     }
 
-    println!("Day 11 Part 1: {}", part_1_result);
-    println!("Day 11 Part 2: {}", part_2_result);
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
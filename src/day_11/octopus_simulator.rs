@@ -1,30 +1,16 @@
-use crate::util::{location::Location, point_2d::Point2d};
-use std::collections::{HashMap, HashSet, VecDeque};
-
-const RELATIVE_NEIGHBOR_LOCATIONS: [Point2d<i32>; 8] = [
-    Point2d { x: 0, y: 1 },
-    Point2d { x: 1, y: 1 },
-    Point2d { x: 1, y: 0 },
-    Point2d { x: 1, y: -1 },
-    Point2d { x: 0, y: -1 },
-    Point2d { x: -1, y: -1 },
-    Point2d { x: -1, y: 0 },
-    Point2d { x: -1, y: 1 },
-];
-
-#[derive(Debug, PartialEq)]
+use crate::util::grid::Grid;
+use crate::util::parse_error::ParseError;
+use crate::util::point_2d::Point2d;
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, PartialEq, Clone)]
 struct Octopus {
     energy_level: u32,
 }
 
 impl Octopus {
-    fn new_from(energy_level_char: char) -> Octopus {
-        Octopus {
-            energy_level: energy_level_char.to_digit(10).expect(&format!(
-                "Could not parse energy level: {}",
-                energy_level_char
-            )),
-        }
+    fn new(energy_level: u32) -> Octopus {
+        Octopus { energy_level }
     }
 
     fn increase_energy_level(&mut self, increase: u32) -> u32 {
@@ -40,34 +26,29 @@ impl Octopus {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct OctopusFlashSimulator {
-    octopi: HashMap<Point2d<i32>, Octopus>,
+    octopi: Grid<Octopus>,
     number_flashed_so_far: u32,
 }
 
-impl OctopusFlashSimulator {
-    pub fn new(octopi_energy_levels: &[String]) -> OctopusFlashSimulator {
-        let mut map = HashMap::new();
-
-        for (row_index, row) in octopi_energy_levels.iter().enumerate() {
-            for (column_index, column) in row.chars().enumerate() {
-                let point = Point2d::new(column_index as i32, row_index as i32);
-
-                let octopus = Octopus::new_from(column);
+impl TryFrom<&[String]> for OctopusFlashSimulator {
+    type Error = ParseError;
 
-                map.insert(point, octopus);
-            }
-        }
+    fn try_from(octopi_energy_levels: &[String]) -> Result<OctopusFlashSimulator, ParseError> {
+        let octopi = Grid::from_digit_lines(octopi_energy_levels)?
+            .map(|&energy_level| Octopus::new(energy_level));
 
-        OctopusFlashSimulator {
-            octopi: map,
+        Ok(OctopusFlashSimulator {
+            octopi,
             number_flashed_so_far: 0,
-        }
+        })
     }
+}
 
+impl OctopusFlashSimulator {
     pub fn get_number_of_octopi(&self) -> u32 {
-        self.octopi.len() as u32
+        (self.octopi.get_width() * self.octopi.get_height()) as u32
     }
 
     fn execute_one_step(&mut self) -> u32 {
@@ -78,26 +59,28 @@ impl OctopusFlashSimulator {
             let octopus_energy_level = octopus.increase_energy_level(1);
 
             if octopus_energy_level > 9 {
-                flashing_octopi_locations.push_back(*location);
+                flashing_octopi_locations.push_back(location);
             }
         }
 
-        while !flashing_octopi_locations.is_empty() {
-            let flashing_octopi_location = flashing_octopi_locations.pop_front().unwrap();
-
+        while let Some(flashing_octopi_location) = flashing_octopi_locations.pop_front() {
             if already_flashed_octopi_locations.contains(&flashing_octopi_location) {
                 continue;
             }
 
-            let flash_energy = self
-                .octopi
-                .get_mut(&flashing_octopi_location)
-                .unwrap()
-                .flash();
+            let flash_energy = match self.octopi.get_mut(&flashing_octopi_location) {
+                Some(octopus) => octopus.flash(),
+                None => continue,
+            };
 
             already_flashed_octopi_locations.insert(flashing_octopi_location);
 
-            for neighbor_location in Self::get_neighbor_locations(&flashing_octopi_location) {
+            let neighbor_locations: Vec<Point2d<i32>> = self
+                .octopi
+                .get_neighbors_with_diagonals(&flashing_octopi_location)
+                .collect();
+
+            for neighbor_location in neighbor_locations {
                 if already_flashed_octopi_locations.contains(&neighbor_location) {
                     continue;
                 }
@@ -115,13 +98,6 @@ impl OctopusFlashSimulator {
 
         already_flashed_octopi_locations.len() as u32
     }
-
-    fn get_neighbor_locations(center_location: &Point2d<i32>) -> Vec<Point2d<i32>> {
-        RELATIVE_NEIGHBOR_LOCATIONS
-            .iter()
-            .map(|relative_location| relative_location.add(center_location))
-            .collect()
-    }
 }
 
 impl Iterator for OctopusFlashSimulator {
@@ -137,62 +113,30 @@ impl Iterator for OctopusFlashSimulator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_11::EXAMPLES;
 
     const TEST_SMALL_DATA: [&str; 5] = ["11111", "19991", "19191", "19991", "11111"];
 
-    const TEST_LARGE_DATA: [&str; 10] = [
-        "5483143223",
-        "2745854711",
-        "5264556173",
-        "6141336146",
-        "6357385478",
-        "4167524645",
-        "2176841721",
-        "6882881134",
-        "4846848554",
-        "5283751526",
-    ];
+    fn get_test_octopi(energy_levels: &[&str]) -> Grid<Octopus> {
+        let input: Vec<String> = energy_levels.iter().map(|s| s.to_string()).collect();
+
+        Grid::from_digit_lines(&input)
+            .unwrap()
+            .map(|&energy_level| Octopus::new(energy_level))
+    }
 
     #[test]
-    fn test_octopus_flash_simulator_new() {
+    fn test_octopus_flash_simulator_try_from() {
         let input: Vec<String> = TEST_SMALL_DATA.iter().map(|s| s.to_string()).collect();
 
-        let expected_map: HashMap<Point2d<i32>, Octopus> = vec![
-            (Point2d::new(0, 0), Octopus { energy_level: 1 }),
-            (Point2d::new(1, 0), Octopus { energy_level: 1 }),
-            (Point2d::new(2, 0), Octopus { energy_level: 1 }),
-            (Point2d::new(3, 0), Octopus { energy_level: 1 }),
-            (Point2d::new(4, 0), Octopus { energy_level: 1 }),
-            (Point2d::new(0, 1), Octopus { energy_level: 1 }),
-            (Point2d::new(1, 1), Octopus { energy_level: 9 }),
-            (Point2d::new(2, 1), Octopus { energy_level: 9 }),
-            (Point2d::new(3, 1), Octopus { energy_level: 9 }),
-            (Point2d::new(4, 1), Octopus { energy_level: 1 }),
-            (Point2d::new(0, 2), Octopus { energy_level: 1 }),
-            (Point2d::new(1, 2), Octopus { energy_level: 9 }),
-            (Point2d::new(2, 2), Octopus { energy_level: 1 }),
-            (Point2d::new(3, 2), Octopus { energy_level: 9 }),
-            (Point2d::new(4, 2), Octopus { energy_level: 1 }),
-            (Point2d::new(0, 3), Octopus { energy_level: 1 }),
-            (Point2d::new(1, 3), Octopus { energy_level: 9 }),
-            (Point2d::new(2, 3), Octopus { energy_level: 9 }),
-            (Point2d::new(3, 3), Octopus { energy_level: 9 }),
-            (Point2d::new(4, 3), Octopus { energy_level: 1 }),
-            (Point2d::new(0, 4), Octopus { energy_level: 1 }),
-            (Point2d::new(1, 4), Octopus { energy_level: 1 }),
-            (Point2d::new(2, 4), Octopus { energy_level: 1 }),
-            (Point2d::new(3, 4), Octopus { energy_level: 1 }),
-            (Point2d::new(4, 4), Octopus { energy_level: 1 }),
-        ]
-        .into_iter()
-        .collect();
+        let expected_map = get_test_octopi(&["11111", "19991", "19191", "19991", "11111"]);
 
         let expected = OctopusFlashSimulator {
             octopi: expected_map,
             number_flashed_so_far: 0,
         };
 
-        let result = OctopusFlashSimulator::new(&input);
+        let result = OctopusFlashSimulator::try_from(&input[..]).unwrap();
 
         assert_eq!(result, expected);
     }
@@ -201,38 +145,10 @@ mod tests {
     fn test_octopus_flash_simulator_execute_one_step() {
         let input: Vec<String> = TEST_SMALL_DATA.iter().map(|s| s.to_string()).collect();
 
-        let mut simulator = OctopusFlashSimulator::new(&input);
+        let mut simulator = OctopusFlashSimulator::try_from(&input[..]).unwrap();
 
         let expected_number_of_flashes = 9;
-        let expected_map: HashMap<Point2d<i32>, Octopus> = vec![
-            (Point2d::new(0, 0), Octopus { energy_level: 3 }),
-            (Point2d::new(1, 0), Octopus { energy_level: 4 }),
-            (Point2d::new(2, 0), Octopus { energy_level: 5 }),
-            (Point2d::new(3, 0), Octopus { energy_level: 4 }),
-            (Point2d::new(4, 0), Octopus { energy_level: 3 }),
-            (Point2d::new(0, 1), Octopus { energy_level: 4 }),
-            (Point2d::new(1, 1), Octopus { energy_level: 0 }),
-            (Point2d::new(2, 1), Octopus { energy_level: 0 }),
-            (Point2d::new(3, 1), Octopus { energy_level: 0 }),
-            (Point2d::new(4, 1), Octopus { energy_level: 4 }),
-            (Point2d::new(0, 2), Octopus { energy_level: 5 }),
-            (Point2d::new(1, 2), Octopus { energy_level: 0 }),
-            (Point2d::new(2, 2), Octopus { energy_level: 0 }),
-            (Point2d::new(3, 2), Octopus { energy_level: 0 }),
-            (Point2d::new(4, 2), Octopus { energy_level: 5 }),
-            (Point2d::new(0, 3), Octopus { energy_level: 4 }),
-            (Point2d::new(1, 3), Octopus { energy_level: 0 }),
-            (Point2d::new(2, 3), Octopus { energy_level: 0 }),
-            (Point2d::new(3, 3), Octopus { energy_level: 0 }),
-            (Point2d::new(4, 3), Octopus { energy_level: 4 }),
-            (Point2d::new(0, 4), Octopus { energy_level: 3 }),
-            (Point2d::new(1, 4), Octopus { energy_level: 4 }),
-            (Point2d::new(2, 4), Octopus { energy_level: 5 }),
-            (Point2d::new(3, 4), Octopus { energy_level: 4 }),
-            (Point2d::new(4, 4), Octopus { energy_level: 3 }),
-        ]
-        .into_iter()
-        .collect();
+        let expected_map = get_test_octopi(&["34543", "40004", "50005", "40004", "34543"]);
 
         let result_number_of_flashes = simulator.execute_one_step();
         let result_map = simulator.octopi;
@@ -243,9 +159,9 @@ mod tests {
 
     #[test]
     fn test_octopus_flash_simulator_iter_10() {
-        let input: Vec<String> = TEST_LARGE_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
-        let simulator = OctopusFlashSimulator::new(&input);
+        let simulator = OctopusFlashSimulator::try_from(&input[..]).unwrap();
 
         let expected = 204;
 
@@ -256,9 +172,9 @@ mod tests {
 
     #[test]
     fn test_octopus_flash_simulator_iter_100() {
-        let input: Vec<String> = TEST_LARGE_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
-        let simulator = OctopusFlashSimulator::new(&input);
+        let simulator = OctopusFlashSimulator::try_from(&input[..]).unwrap();
 
         let expected = 1656;
 
@@ -269,9 +185,9 @@ mod tests {
 
     #[test]
     fn test_octopus_flash_simulator_iter_when_all_flash() {
-        let input: Vec<String> = TEST_LARGE_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
-        let simulator = OctopusFlashSimulator::new(&input);
+        let simulator = OctopusFlashSimulator::try_from(&input[..]).unwrap();
 
         let number_of_octopi = simulator.get_number_of_octopi();
 
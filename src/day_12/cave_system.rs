@@ -1,4 +1,8 @@
-use std::collections::{HashMap, HashSet};
+use crate::util::parse_error::ParseError;
+use crate::util::search::count_paths;
+use std::collections::{BTreeSet, HashMap};
+
+const CONNECTION_FORMAT: &str = "cave-cave";
 
 #[derive(Debug, PartialEq)]
 enum CaveType {
@@ -47,11 +51,24 @@ impl Cave {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct PathState<'a> {
+    cave_name: &'a str,
+    small_caves_visited: BTreeSet<&'a str>,
+    can_revisit_small_cave: bool,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CaveSystem {
     cave_names_to_caves: HashMap<String, Cave>,
 }
 
+impl Default for CaveSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CaveSystem {
     pub fn new() -> CaveSystem {
         CaveSystem {
@@ -59,140 +76,83 @@ impl CaveSystem {
         }
     }
 
-    pub fn new_from(cave_descriptions: &[String]) -> CaveSystem {
-        let mut result = CaveSystem::new();
-
-        for description in cave_descriptions {
-            result.add_caves(description);
-        }
-
-        result
-    }
-
-    pub fn number_of_paths_to_end_visiting_small_caves_once(&self) -> u32 {
-        let start_cave = self
-            .cave_names_to_caves
-            .get("start")
-            .expect("Start cave is missing!");
-        let mut small_caves_visited = HashSet::new();
-
-        return self.number_of_paths_to_end_visiting_small_caves_once_from(
-            start_cave,
-            &mut small_caves_visited,
-        );
+    pub fn number_of_paths_to_end_visiting_small_caves_once(&self) -> Option<usize> {
+        self.number_of_paths_to_end_from("start", false)
     }
 
-    pub fn number_of_paths_to_end_visiting_small_caves_once_maybe_twice(&self) -> u32 {
-        let start_cave = self
-            .cave_names_to_caves
-            .get("start")
-            .expect("Start cave is missing!");
-        let mut small_caves_visited = HashSet::new();
-
-        return self.number_of_paths_to_end_visiting_small_caves_once_maybe_twice_from(
-            start_cave,
-            &mut small_caves_visited,
-            None,
-        );
+    pub fn number_of_paths_to_end_visiting_small_caves_once_maybe_twice(&self) -> Option<usize> {
+        self.number_of_paths_to_end_from("start", true)
     }
 
-    fn number_of_paths_to_end_visiting_small_caves_once_from(
+    fn number_of_paths_to_end_from(
         &self,
-        cave: &Cave,
-        small_caves_visited: &mut HashSet<String>,
-    ) -> u32 {
-        if cave.is_end() {
-            return 1;
-        }
-
-        if cave.is_small() {
-            small_caves_visited.insert(cave.name.clone());
-        }
-
-        let mut result = 0;
-
-        for connected_cave_name in cave.connected_cave_names.iter() {
-            if small_caves_visited.contains(connected_cave_name) {
-                continue;
-            }
-
-            let connected_cave = self
-                .cave_names_to_caves
-                .get(connected_cave_name)
-                .expect(&format!("Could not get cave: {}", connected_cave_name));
+        cave_name: &str,
+        can_revisit_small_cave: bool,
+    ) -> Option<usize> {
+        let mut start = PathState {
+            cave_name,
+            small_caves_visited: BTreeSet::new(),
+            can_revisit_small_cave,
+        };
 
-            result += self.number_of_paths_to_end_visiting_small_caves_once_from(
-                connected_cave,
-                small_caves_visited,
-            );
+        if self.get_cave(cave_name).is_small() {
+            start.small_caves_visited.insert(cave_name);
         }
 
-        small_caves_visited.remove(&cave.name);
-
-        result
+        count_paths(
+            start,
+            |state| self.get_next_path_states(state),
+            |state| self.get_cave(state.cave_name).is_end(),
+        )
     }
 
-    fn number_of_paths_to_end_visiting_small_caves_once_maybe_twice_from(
-        &self,
-        cave: &Cave,
-        small_caves_visited: &mut HashSet<String>,
-        small_cave_seen_twice: Option<String>,
-    ) -> u32 {
-        if cave.is_end() {
-            return 1;
-        }
-
-        if cave.is_small() {
-            small_caves_visited.insert(cave.name.clone());
-        }
+    fn get_next_path_states<'a>(&'a self, state: &PathState<'a>) -> Vec<PathState<'a>> {
+        let mut result = Vec::new();
 
-        let mut result = 0;
-
-        for connected_cave_name in cave.connected_cave_names.iter() {
-            let connected_cave = self
-                .cave_names_to_caves
-                .get(connected_cave_name)
-                .expect(&format!("Could not get cave: {}", connected_cave_name));
-
-            if small_caves_visited.contains(connected_cave_name) && small_cave_seen_twice.is_some()
-            {
-                continue;
-            }
+        for connected_cave_name in self.get_cave(state.cave_name).connected_cave_names.iter() {
+            let connected_cave = self.get_cave(connected_cave_name);
 
             if connected_cave.is_start() {
                 continue;
             }
 
-            let seen_twice = if small_cave_seen_twice.is_some() {
-                small_cave_seen_twice.clone()
-            } else if small_caves_visited.contains(connected_cave_name) {
-                Some(connected_cave_name.clone())
-            } else {
-                None
+            let mut next_state = PathState {
+                cave_name: &connected_cave.name,
+                small_caves_visited: state.small_caves_visited.clone(),
+                can_revisit_small_cave: state.can_revisit_small_cave,
             };
 
-            result += self.number_of_paths_to_end_visiting_small_caves_once_maybe_twice_from(
-                connected_cave,
-                small_caves_visited,
-                seen_twice,
-            );
-        }
+            if connected_cave.is_small()
+                && !next_state.small_caves_visited.insert(&connected_cave.name)
+            {
+                if !next_state.can_revisit_small_cave {
+                    continue;
+                }
 
-        if let Some(twice_seen_cave_name) = small_cave_seen_twice {
-            if twice_seen_cave_name != cave.name {
-                small_caves_visited.remove(&cave.name);
+                next_state.can_revisit_small_cave = false;
             }
-        } else {
-            small_caves_visited.remove(&cave.name);
+
+            result.push(next_state);
         }
 
         result
     }
 
-    fn add_caves(&mut self, description: &str) {
+    fn get_cave(&self, cave_name: &str) -> &Cave {
+        self.cave_names_to_caves
+            .get(cave_name)
+            .unwrap_or_else(|| panic!("Could not get cave: {}", cave_name))
+    }
+
+    fn add_caves(&mut self, description: &str) -> Result<(), ParseError> {
         let (cave_1_name, cave_2_name) = description
             .split_once('-')
-            .expect(&format!("Could not split: {}", description));
+            .filter(|(cave_1_name, cave_2_name)| {
+                [cave_1_name, cave_2_name]
+                    .iter()
+                    .all(|name| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()))
+            })
+            .ok_or_else(|| ParseError::expected(description, CONNECTION_FORMAT))?;
 
         let cave_1 = self
             .cave_names_to_caves
@@ -207,23 +167,40 @@ impl CaveSystem {
             .or_insert(Cave::new(cave_2_name));
 
         cave_2.connect_cave_name(cave_1_name);
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&[String]> for CaveSystem {
+    type Error = ParseError;
+
+    fn try_from(cave_descriptions: &[String]) -> Result<CaveSystem, ParseError> {
+        let mut result = CaveSystem::new();
+
+        for (line_index, description) in cave_descriptions.iter().enumerate() {
+            result
+                .add_caves(description)
+                .map_err(|error| error.at_line(line_index + 1))?;
+        }
+
+        for required_cave_name in ["start", "end"] {
+            if !result.cave_names_to_caves.contains_key(required_cave_name) {
+                return Err(ParseError::new(
+                    "",
+                    &format!("expected a connection to the '{}' cave", required_cave_name),
+                ));
+            }
+        }
+
+        Ok(result)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA_SMALL: [&str; 7] =
-        ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
-    const TEST_DATA_MEDIUM: [&str; 10] = [
-        "dc-end", "HN-start", "start-kj", "dc-start", "dc-HN", "LN-dc", "HN-end", "kj-sa", "kj-HN",
-        "kj-dc",
-    ];
-    const TEST_DATA_LARGE: [&str; 18] = [
-        "fs-end", "he-DX", "fs-he", "start-DX", "pj-DX", "end-zg", "zg-sl", "zg-pj", "pj-he",
-        "RW-he", "fs-DX", "pj-RW", "zg-RW", "start-pj", "he-WI", "zg-he", "pj-fs", "start-RW",
-    ];
+    use crate::day_12::EXAMPLES;
 
     #[test]
     fn test_cave_new() {
@@ -249,8 +226,8 @@ mod tests {
     fn test_cave_system_add_caves() {
         let mut test_system = CaveSystem::new();
 
-        test_system.add_caves(TEST_DATA_SMALL[0]);
-        test_system.add_caves(TEST_DATA_SMALL[1]);
+        test_system.add_caves("start-A").unwrap();
+        test_system.add_caves("start-b").unwrap();
 
         let mut start_cave = Cave::new("start");
         let mut a_cave_large = Cave::new("A");
@@ -278,8 +255,8 @@ mod tests {
     }
 
     #[test]
-    fn test_cave_system_new_from() {
-        let test_system = get_test_cave_system(&TEST_DATA_SMALL);
+    fn test_cave_system_try_from() {
+        let test_system = get_example_cave_system(0);
 
         let mut start_cave = Cave::new("start");
         let mut a_cave_large = Cave::new("A");
@@ -328,61 +305,66 @@ mod tests {
     }
 
     #[test]
-    fn test_cave_system_number_of_paths_to_end_visiting_small_caves_once_from_zero() {
-        let test_system = get_test_cave_system(&TEST_DATA_SMALL);
+    fn test_cave_system_try_from_errors() {
+        let bad_connection: Vec<String> = vec![String::from("start-A"), String::from("A->end")];
+        let missing_end: Vec<String> = vec![String::from("start-A")];
+
+        let expected = vec![
+            String::from("2: expected 'cave-cave'"),
+            String::from("expected a connection to the 'end' cave"),
+        ];
+
+        let result = vec![
+            CaveSystem::try_from(&bad_connection[..])
+                .unwrap_err()
+                .to_string(),
+            CaveSystem::try_from(&missing_end[..])
+                .unwrap_err()
+                .to_string(),
+        ];
 
-        let d_cave = test_system.cave_names_to_caves.get("d").unwrap();
-        let mut seen_small_caves = vec![String::from("start"), String::from("b")]
-            .into_iter()
-            .collect();
+        assert_eq!(result, expected);
+    }
 
-        let expected = 0;
-        let result = test_system
-            .number_of_paths_to_end_visiting_small_caves_once_from(d_cave, &mut seen_small_caves);
+    #[test]
+    fn test_cave_system_number_of_paths_to_end_from_dead_end() {
+        let test_system = get_example_cave_system(0);
+
+        let expected = Some(3);
+        let result = test_system.number_of_paths_to_end_from("d", false);
 
         assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_cave_system_number_of_paths_to_end_visiting_small_caves_once_from_not_zero() {
-        let test_system = get_test_cave_system(&TEST_DATA_SMALL);
+    fn test_cave_system_number_of_paths_to_end_from_not_zero() {
+        let test_system = get_example_cave_system(0);
 
-        let b_cave = test_system.cave_names_to_caves.get("b").unwrap();
-        let mut seen_small_caves = vec![String::from("start")].into_iter().collect();
-
-        let expected = 3;
-        let result = test_system
-            .number_of_paths_to_end_visiting_small_caves_once_from(b_cave, &mut seen_small_caves);
+        let expected = Some(3);
+        let result = test_system.number_of_paths_to_end_from("b", false);
 
         assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_cave_system_number_of_paths_to_end_visiting_small_caves_once_maybe_twice_from() {
-        let test_system = get_test_cave_system(&TEST_DATA_SMALL);
-
-        let b_cave = test_system.cave_names_to_caves.get("b").unwrap();
-        let mut seen_small_caves = vec![String::from("start")].into_iter().collect();
+    fn test_cave_system_number_of_paths_to_end_from_maybe_twice() {
+        let test_system = get_example_cave_system(0);
 
-        let expected = 12;
-        let result = test_system.number_of_paths_to_end_visiting_small_caves_once_maybe_twice_from(
-            b_cave,
-            &mut seen_small_caves,
-            None,
-        );
+        let expected = Some(12);
+        let result = test_system.number_of_paths_to_end_from("b", true);
 
         assert_eq!(result, expected);
     }
 
     #[test]
     fn test_cave_system_number_of_paths_to_end_visiting_small_caves_once() {
-        let small_system = get_test_cave_system(&TEST_DATA_SMALL);
-        let medium_system = get_test_cave_system(&TEST_DATA_MEDIUM);
-        let large_system = get_test_cave_system(&TEST_DATA_LARGE);
+        let small_system = get_example_cave_system(0);
+        let medium_system = get_example_cave_system(1);
+        let large_system = get_example_cave_system(2);
 
-        let expected_small = 10;
-        let expected_medium = 19;
-        let expected_large = 226;
+        let expected_small = Some(10);
+        let expected_medium = Some(19);
+        let expected_large = Some(226);
 
         let result_small = small_system.number_of_paths_to_end_visiting_small_caves_once();
         let result_medium = medium_system.number_of_paths_to_end_visiting_small_caves_once();
@@ -395,13 +377,13 @@ mod tests {
 
     #[test]
     fn test_cave_system_number_of_paths_to_end_visiting_small_caves_once_maybe_twice() {
-        let small_system = get_test_cave_system(&TEST_DATA_SMALL);
-        let medium_system = get_test_cave_system(&TEST_DATA_MEDIUM);
-        let large_system = get_test_cave_system(&TEST_DATA_LARGE);
+        let small_system = get_example_cave_system(0);
+        let medium_system = get_example_cave_system(1);
+        let large_system = get_example_cave_system(2);
 
-        let expected_small = 36;
-        let expected_medium = 103;
-        let expected_large = 3509;
+        let expected_small = Some(36);
+        let expected_medium = Some(103);
+        let expected_large = Some(3509);
 
         let result_small =
             small_system.number_of_paths_to_end_visiting_small_caves_once_maybe_twice();
@@ -414,9 +396,26 @@ mod tests {
         assert_eq!(result_medium, expected_medium);
         assert_eq!(result_large, expected_large);
     }
+
+    #[test]
+    fn test_cave_system_number_of_paths_with_connected_large_caves() {
+        let test_system = get_test_cave_system(&["start-A", "A-B", "B-end"]);
+
+        let result_once = test_system.number_of_paths_to_end_visiting_small_caves_once();
+        let result_maybe_twice =
+            test_system.number_of_paths_to_end_visiting_small_caves_once_maybe_twice();
+
+        assert_eq!(result_once, None);
+        assert_eq!(result_maybe_twice, None);
+    }
+
     fn get_test_cave_system(data: &[&str]) -> CaveSystem {
         let input: Vec<String> = data.iter().map(|s| s.to_string()).collect();
 
-        CaveSystem::new_from(&input)
+        CaveSystem::try_from(&input[..]).unwrap()
+    }
+
+    fn get_example_cave_system(index: usize) -> CaveSystem {
+        CaveSystem::try_from(&EXAMPLES[index].get_lines()[..]).unwrap()
     }
 }
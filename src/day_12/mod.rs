@@ -1,18 +1,90 @@
-#![allow(unused_imports)]
 mod cave_system;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use cave_system::CaveSystem;
 
-use cave_system::CaveSystem;
+const EXAMPLES: [Example; 3] = [
+    Example {
+        input: "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end",
+        part_one: Some("10"),
+        part_two: Some("36"),
+    },
+    Example {
+        input: "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc",
+        part_one: Some("19"),
+        part_two: Some("103"),
+    },
+    Example {
+        input: "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW",
+        part_one: Some("226"),
+        part_two: Some("3509"),
+    },
+];
 
-pub fn run_day_12() {
-    let file_input = to_string_vector("inputs/day_12.txt").unwrap();
+pub struct Day12;
 
-    let cave_system = CaveSystem::new_from(&file_input);
+impl Solution for Day12 {
+    type Input = CaveSystem;
 
-    let part_1_result = cave_system.number_of_paths_to_end_visiting_small_caves_once();
-    let part_2_result = cave_system.number_of_paths_to_end_visiting_small_caves_once_maybe_twice();
+    fn parse(&self, input: &[String]) -> Result<CaveSystem, ParseError> {
+        CaveSystem::try_from(input)
+    }
 
-    println!("Day 12 Part 1: {}", part_1_result);
-    println!("Day 12 Part 2: {}", part_2_result);
+    fn part_one(&self, cave_system: &CaveSystem) -> Result<Answer, String> {
+        cave_system
+            .number_of_paths_to_end_visiting_small_caves_once()
+            .map(Answer::from)
+            .ok_or_else(get_cycle_error)
+    }
+
+    fn part_two(&self, cave_system: &CaveSystem) -> Result<Answer, String> {
+        cave_system
+            .number_of_paths_to_end_visiting_small_caves_once_maybe_twice()
+            .map(Answer::from)
+            .ok_or_else(get_cycle_error)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+}
+
+fn get_cycle_error() -> String {
+    String::from("Large caves are connected, so there are infinitely many paths!")
 }
@@ -1,18 +1,59 @@
 mod transparent_paper;
 
-use crate::util::file_reader::to_string_vector;
-use transparent_paper::{display_transparent_paper, get_number_of_dots_at_each_fold};
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use transparent_paper::{
+    get_number_of_dots_at_each_fold, get_transparent_paper_display, FoldingInstructions,
+};
 
-pub fn run_day_13() {
-    let file_input = to_string_vector("inputs/day_13.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
 
-    let number_of_dots_at_each_fold = get_number_of_dots_at_each_fold(&file_input);
+fold along y=7
+fold along x=5",
+    part_one: Some("17"),
+    part_two: Some("#####\n#   #\n#   #\n#   #\n#####"),
+}];
 
-    let part_1_result = number_of_dots_at_each_fold
-        .first()
-        .expect("Array is empty!");
+pub struct Day13;
 
-    println!("Day 13 Part 1: {}", part_1_result);
-    println!("Day 13 Part 2:");
-    display_transparent_paper(&file_input);
+impl Solution for Day13 {
+    type Input = FoldingInstructions;
+
+    fn parse(&self, input: &[String]) -> Result<FoldingInstructions, ParseError> {
+        FoldingInstructions::try_from(input)
+    }
+
+    fn part_one(&self, instructions: &FoldingInstructions) -> Result<Answer, String> {
+        get_number_of_dots_at_each_fold(instructions)
+            .first()
+            .map(|&number_of_dots| number_of_dots.into())
+            .ok_or_else(|| String::from("No fold instructions!"))
+    }
+
+    fn part_two(&self, instructions: &FoldingInstructions) -> Result<Answer, String> {
+        get_transparent_paper_display(instructions).map(Answer::from)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -1,13 +1,17 @@
+use crate::util::file_reader::get_indexed_sections;
+use crate::util::parse_error::{parse_number, ParseError};
 use crate::util::point_2d::Point2d;
 use std::collections::HashSet;
 
-#[derive(Debug, PartialEq)]
+const FOLD_FORMAT: &str = "fold along x|y=value";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 enum Fold {
     Horizontal(i32),
     Vertical(i32),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 struct TransparentPaper {
     dots: HashSet<Point2d<i32>>,
 }
@@ -58,26 +62,52 @@ impl TransparentPaper {
         (fold_value - (value % fold_value)) % fold_value
     }
 
-    fn get_furthest_location(&self) -> Point2d<i32> {
-        *self.dots.iter().max().unwrap()
+    fn get_furthest_location(&self) -> Option<Point2d<i32>> {
+        let max_x = self.dots.iter().map(|location| location.x).max()?;
+        let max_y = self.dots.iter().map(|location| location.y).max()?;
+
+        Some(Point2d::new(max_x, max_y))
     }
 }
 
-pub fn get_number_of_dots_at_each_fold(instructions: &[String]) -> Vec<usize> {
-    let mut paper = TransparentPaper::new();
-    let mut folds = Vec::new();
+#[derive(Debug, PartialEq, Clone)]
+pub struct FoldingInstructions {
+    paper: TransparentPaper,
+    folds: Vec<Fold>,
+}
+
+impl TryFrom<&[String]> for FoldingInstructions {
+    type Error = ParseError;
+
+    fn try_from(instructions: &[String]) -> Result<FoldingInstructions, ParseError> {
+        let mut paper = TransparentPaper::new();
+        let mut folds = Vec::new();
+
+        for (section_index, (first_line_index, section)) in
+            get_indexed_sections(instructions).into_iter().enumerate()
+        {
+            for (line_index, instruction) in section.iter().enumerate() {
+                let at_line = |error: ParseError| error.at_line(first_line_index + line_index + 1);
 
-    for instruction in instructions.iter().filter(|s| !s.is_empty()) {
-        if instruction.starts_with("fold") {
-            folds.push(get_fold(instruction));
-        } else {
-            paper.add_dot(get_point(instruction));
+                if section_index == 0 {
+                    paper.add_dot(get_dot(instruction).map_err(at_line)?);
+                } else {
+                    folds.push(get_fold(instruction).map_err(at_line)?);
+                }
+            }
         }
+
+        Ok(FoldingInstructions { paper, folds })
     }
+}
 
-    folds
-        .into_iter()
-        .map(|fold| {
+pub fn get_number_of_dots_at_each_fold(instructions: &FoldingInstructions) -> Vec<usize> {
+    let mut paper = instructions.paper.clone();
+
+    instructions
+        .folds
+        .iter()
+        .map(|&fold| {
             paper.fold(fold);
 
             paper.get_number_of_dots()
@@ -85,74 +115,61 @@ pub fn get_number_of_dots_at_each_fold(instructions: &[String]) -> Vec<usize> {
         .collect()
 }
 
-pub fn display_transparent_paper(instructions: &[String]) {
-    let mut paper = TransparentPaper::new();
-    let mut folds = Vec::new();
-
-    for instruction in instructions.iter().filter(|s| !s.is_empty()) {
-        if instruction.starts_with("fold") {
-            folds.push(get_fold(instruction));
-        } else {
-            paper.add_dot(get_point(instruction));
-        }
-    }
+pub fn get_transparent_paper_display(instructions: &FoldingInstructions) -> Result<String, String> {
+    let mut paper = instructions.paper.clone();
 
-    folds.into_iter().for_each(|fold| paper.fold(fold));
+    instructions.folds.iter().for_each(|&fold| paper.fold(fold));
 
-    let furthest_location = paper.get_furthest_location();
+    let furthest_location = paper
+        .get_furthest_location()
+        .ok_or_else(|| String::from("No dots on the paper!"))?;
 
     let row_template = vec![' '; (furthest_location.x + 1) as usize];
-    let mut display_grid = vec![row_template.clone(); (furthest_location.y + 1) as usize];
+    let mut display_grid = vec![row_template; (furthest_location.y + 1) as usize];
 
     for location in paper.dots.iter() {
-        let row = display_grid
-            .get_mut(location.y as usize)
-            .expect(&format!("Could not get row: {}", location.y));
-
-        let element = row
-            .get_mut(location.x as usize)
-            .expect(&format!("Could not get element at: {:?}", location));
-
-        *element = '#';
+        display_grid[location.y as usize][location.x as usize] = '#';
     }
 
-    for row in display_grid {
-        for element in row {
-            print!("{}", element);
-        }
-
-        print!("\n");
-    }
+    Ok(display_grid
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n"))
 }
 
-fn get_point(input_line: &str) -> Point2d<i32> {
-    let (x_str, y_str) = input_line
-        .split_once(',')
-        .expect(&format!("Could not split: {}", input_line));
+fn get_dot(input_line: &str) -> Result<Point2d<i32>, ParseError> {
+    let dot: Point2d<i32> = input_line.parse()?;
 
-    let x = x_str
-        .parse()
-        .expect(&format!("Could not parse x: {}", x_str));
-    let y = y_str
-        .parse()
-        .expect(&format!("Could not parse y: {}", y_str));
+    if (dot.x < 0) || (dot.y < 0) {
+        return Err(ParseError::new(
+            input_line,
+            &format!("expected a non-negative dot, but got '{}'", input_line),
+        ));
+    }
 
-    Point2d::new(x, y)
+    Ok(dot)
 }
 
-fn get_fold(input_line: &str) -> Fold {
-    let (fold_indicator, fold_value_str) = input_line[11..]
-        .split_once('=')
-        .expect(&format!("Could not split: {}", input_line));
+fn get_fold(input_line: &str) -> Result<Fold, ParseError> {
+    let (fold_indicator, fold_value_str) = input_line
+        .strip_prefix("fold along ")
+        .and_then(|fold| fold.split_once('='))
+        .ok_or_else(|| ParseError::expected(input_line, FOLD_FORMAT))?;
 
-    let fold_value: i32 = fold_value_str
-        .parse()
-        .expect(&format!("Could not parse fold value: {}", fold_value_str));
+    let fold_value: i32 = parse_number(fold_value_str)?;
+
+    if fold_value <= 0 {
+        return Err(ParseError::new(
+            input_line,
+            &format!("expected a positive fold value, but got {}", fold_value),
+        ));
+    }
 
     match fold_indicator {
-        "y" => Fold::Horizontal(fold_value),
-        "x" => Fold::Vertical(fold_value),
-        _ => panic!("Could not match {} to a fold!", fold_indicator),
+        "y" => Ok(Fold::Horizontal(fold_value)),
+        "x" => Ok(Fold::Vertical(fold_value)),
+        _ => Err(ParseError::expected(input_line, FOLD_FORMAT)),
     }
 }
 
@@ -161,30 +178,7 @@ mod tests {
     use std::vec;
 
     use super::*;
-
-    const TEST_DATA: [&str; 21] = [
-        "6,10",
-        "0,14",
-        "9,10",
-        "0,3",
-        "10,4",
-        "4,11",
-        "6,0",
-        "6,12",
-        "4,1",
-        "0,13",
-        "10,12",
-        "3,4",
-        "3,0",
-        "8,4",
-        "1,10",
-        "2,14",
-        "8,10",
-        "9,0",
-        "",
-        "fold along y=7",
-        "fold along x=5",
-    ];
+    use crate::day_13::EXAMPLES;
 
     #[test]
     fn test_transparent_paper_fold_horizontal() {
@@ -295,7 +289,7 @@ mod tests {
     }
     #[test]
     fn test_get_number_of_dots_at_each_fold() {
-        let instructions: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let instructions = get_test_instructions();
 
         let expected = vec![17, 16];
 
@@ -305,12 +299,24 @@ mod tests {
     }
 
     #[test]
-    fn test_get_point() {
-        let expected = Point2d::new(6, 10);
+    fn test_get_transparent_paper_display() {
+        let instructions = get_test_instructions();
 
-        let result = get_point(TEST_DATA[0]);
+        let expected = ["#####", "#   #", "#   #", "#   #", "#####"].join("\n");
 
-        assert_eq!(result, expected);
+        let result = get_transparent_paper_display(&instructions);
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_get_transparent_paper_display_error() {
+        let instructions = FoldingInstructions {
+            paper: TransparentPaper::new(),
+            folds: vec![Fold::Horizontal(7)],
+        };
+
+        assert!(get_transparent_paper_display(&instructions).is_err());
     }
 
     #[test]
@@ -318,10 +324,44 @@ mod tests {
         let expected_horizonal_fold = Fold::Horizontal(7);
         let expected_vertical_fold = Fold::Vertical(5);
 
-        let result_horizontal_fold = get_fold(TEST_DATA[19]);
-        let result_vertical_fold = get_fold(TEST_DATA[20]);
+        let result_horizontal_fold = get_fold("fold along y=7");
+        let result_vertical_fold = get_fold("fold along x=5");
+
+        assert_eq!(result_horizontal_fold, Ok(expected_horizonal_fold));
+        assert_eq!(result_vertical_fold, Ok(expected_vertical_fold));
+        assert!(get_fold("fold along y=0").is_err());
+        assert!(get_dot("-1,4").is_err());
+    }
+
+    #[test]
+    fn test_folding_instructions_try_from_error() {
+        let expected = vec![
+            "2: expected a non-negative dot, but got '0,-3'",
+            "4: expected 'fold along x|y=value'",
+            "5: expected a positive fold value, but got 0",
+        ];
+
+        let result: Vec<String> = [
+            vec!["6,10", "0,-3", "", "fold along y=7"],
+            vec!["6,10", "0,3", "", "fold along z=5"],
+            vec!["6,10", "0,3", "", "fold along y=7", "fold along x=0"],
+        ]
+        .iter()
+        .map(|lines| {
+            let instructions: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
+
+            FoldingInstructions::try_from(&instructions[..])
+                .unwrap_err()
+                .to_string()
+        })
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    fn get_test_instructions() -> FoldingInstructions {
+        let instructions = EXAMPLES[0].get_lines();
 
-        assert_eq!(result_horizontal_fold, expected_horizonal_fold);
-        assert_eq!(result_vertical_fold, expected_vertical_fold);
+        FoldingInstructions::try_from(&instructions[..]).unwrap()
     }
 }
@@ -1,37 +1,63 @@
 mod polymer_tool;
 
-use crate::util::file_reader::to_string_vector;
-use polymer_tool::{get_template_and_rules, PolymerTool};
-
-pub fn run_day_14() {
-    let file_input = to_string_vector("inputs/day_14.txt").unwrap();
-
-    let polymer_tool = get_tool(&file_input);
-
-    let part_1_result = get_result_after_n_steps(&polymer_tool, 10);
-    let part_2_result = get_result_after_n_steps(&polymer_tool, 40);
-
-    println!("Day 14 Part 1: {}", part_1_result);
-    println!("Day 14 Part 2: {}", part_2_result);
-}
-
-fn get_tool(input: &[String]) -> PolymerTool {
-    let (template, rules) = get_template_and_rules(&input);
-
-    let mut polymer_tool = PolymerTool::new();
-
-    polymer_tool.add_template(&template);
-
-    rules.iter().for_each(|rule| polymer_tool.add_rule(rule));
-
-    polymer_tool
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use polymer_tool::PolymerTool;
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C",
+    part_one: Some("1588"),
+    part_two: Some("2188189693529"),
+}];
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = PolymerTool;
+
+    fn parse(&self, input: &[String]) -> Result<PolymerTool, ParseError> {
+        PolymerTool::try_from(input)
+    }
+
+    fn part_one(&self, tool: &PolymerTool) -> Result<Answer, String> {
+        get_result_after_n_steps(tool, 10).map(Answer::from)
+    }
+
+    fn part_two(&self, tool: &PolymerTool) -> Result<Answer, String> {
+        get_result_after_n_steps(tool, 40).map(Answer::from)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
 
-fn get_result_after_n_steps(tool: &PolymerTool, steps: usize) -> usize {
+fn get_result_after_n_steps(tool: &PolymerTool, steps: usize) -> Result<usize, String> {
     let element_counts = tool.get_element_quantities_after_n_steps(steps);
 
-    let max_value = element_counts.values().max().unwrap();
-    let min_value = element_counts.values().min().unwrap();
+    let max_value = element_counts.values().max();
+    let min_value = element_counts.values().min();
 
-    max_value - min_value
+    match (max_value, min_value) {
+        (Some(max_value), Some(min_value)) => Ok(max_value - min_value),
+        _ => Err(String::from("Polymer template is empty!")),
+    }
 }
@@ -1,4 +1,10 @@
-use std::collections::HashMap;
+use crate::util::file_reader::get_indexed_sections;
+use crate::util::parse_error::ParseError;
+use std::collections::{HashMap, HashSet};
+
+const RULE_FORMAT: &str = "AB -> C";
+
+type Rule = ((char, char), (char, char, char));
 
 #[derive(Debug, PartialEq)]
 pub struct PolymerTool {
@@ -6,6 +12,12 @@ pub struct PolymerTool {
     rules: HashMap<(char, char), (char, char, char)>,
 }
 
+impl Default for PolymerTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PolymerTool {
     pub fn new() -> PolymerTool {
         PolymerTool {
@@ -18,10 +30,12 @@ impl PolymerTool {
         self.template = template.chars().collect();
     }
 
-    pub fn add_rule(&mut self, rule: &str) {
-        let rule = Self::parse_rule(rule);
+    pub fn add_rule(&mut self, rule: &str) -> Result<(), ParseError> {
+        let (pair, insertion): Rule = Self::parse_rule(rule)?;
+
+        self.rules.insert(pair, insertion);
 
-        self.rules.insert(rule.0, rule.1);
+        Ok(())
     }
 
     pub fn get_element_quantities_after_n_steps(&self, steps: usize) -> HashMap<char, usize> {
@@ -41,7 +55,10 @@ impl PolymerTool {
             let mut temp_map = HashMap::new();
 
             for (tuple, count) in working_map.iter() {
-                let insertion_result = self.rules.get(&tuple).unwrap();
+                let insertion_result = self
+                    .rules
+                    .get(tuple)
+                    .expect("Rules cover every reachable pair");
 
                 let tuple_1 = (insertion_result.0, insertion_result.1);
                 let tuple_2 = (insertion_result.1, insertion_result.2);
@@ -75,63 +92,103 @@ impl PolymerTool {
         result
     }
 
+    fn check_rule_coverage(&self) -> Result<(), ParseError> {
+        let mut pairs_to_check: Vec<(char, char)> = self
+            .template
+            .windows(2)
+            .map(Self::parse_window_to_tuple)
+            .collect();
+        let mut seen_pairs = HashSet::new();
+
+        while let Some(pair) = pairs_to_check.pop() {
+            if !seen_pairs.insert(pair) {
+                continue;
+            }
+
+            let &(first, inserted, second) = self.rules.get(&pair).ok_or_else(|| {
+                ParseError::new(
+                    "",
+                    &format!("expected a rule for the pair '{}{}'", pair.0, pair.1),
+                )
+            })?;
+
+            pairs_to_check.push((first, inserted));
+            pairs_to_check.push((inserted, second));
+        }
+
+        Ok(())
+    }
+
     fn parse_window_to_tuple(window: &[char]) -> (char, char) {
         (*window.get(0).unwrap(), *window.get(1).unwrap())
     }
 
-    fn parse_rule(rule: &str) -> ((char, char), (char, char, char)) {
+    fn parse_rule(rule: &str) -> Result<Rule, ParseError> {
         let (lhs, rhs) = rule
             .split_once(" -> ")
-            .expect(&format!("Could not parse rule: {}", rule));
+            .ok_or_else(|| ParseError::expected(rule, RULE_FORMAT))?;
 
-        let (lhs_char_1, lhs_char_2) = (
-            lhs.chars()
-                .nth(0)
-                .expect(&format!("LHS of Rule empty: {}", rule)),
-            lhs.chars()
-                .nth(1)
-                .expect(&format!("LHS of Rule only one character: {}", rule)),
-        );
+        let lhs_chars: Vec<char> = lhs.chars().collect();
+        let rhs_chars: Vec<char> = rhs.chars().collect();
 
-        let rhs_char = rhs
-            .chars()
-            .nth(0)
-            .expect(&format!("RHS of Rule empty: {}", rule));
-
-        ((lhs_char_1, lhs_char_2), (lhs_char_1, rhs_char, lhs_char_2))
+        match (&lhs_chars[..], &rhs_chars[..]) {
+            (&[lhs_char_1, lhs_char_2], &[rhs_char]) => {
+                Ok(((lhs_char_1, lhs_char_2), (lhs_char_1, rhs_char, lhs_char_2)))
+            }
+            _ => Err(ParseError::expected(rule, RULE_FORMAT)),
+        }
     }
 }
 
-pub fn get_template_and_rules(input: &[String]) -> (String, Vec<String>) {
-    let template = input.get(0).expect("Input is empty!").to_string();
+impl TryFrom<&[String]> for PolymerTool {
+    type Error = ParseError;
 
-    let rules = input
-        .iter()
-        .skip(1)
-        .filter(|line| !line.is_empty())
-        .map(|line| line.to_string())
-        .collect();
+    fn try_from(input: &[String]) -> Result<PolymerTool, ParseError> {
+        let sections = get_indexed_sections(input);
+
+        let template = match sections.first() {
+            Some((_, [template])) => template,
+            Some((line_index, section)) => {
+                return Err(
+                    ParseError::new(&section[1], "expected a single template line")
+                        .at_line(line_index + 2),
+                )
+            }
+            None => return Err(ParseError::new("", "expected a polymer template")),
+        };
+
+        let mut polymer_tool = PolymerTool::new();
 
-    (template, rules)
+        polymer_tool.add_template(template);
+
+        for (first_line_index, section) in sections.iter().skip(1) {
+            for (line_index, rule) in section.iter().enumerate() {
+                polymer_tool
+                    .add_rule(rule)
+                    .map_err(|error| error.at_line(first_line_index + line_index + 1))?;
+            }
+        }
+
+        polymer_tool.check_rule_coverage()?;
+
+        Ok(polymer_tool)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: [&str; 18] = [
-        "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B", "HN -> C",
-        "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B", "CC -> N",
-        "CN -> C",
-    ];
+    use crate::day_14::EXAMPLES;
 
     #[test]
     fn test_parse_rule() {
         let expected = (('C', 'H'), ('C', 'B', 'H'));
 
-        let result = PolymerTool::parse_rule(TEST_DATA[2]);
+        let result = PolymerTool::parse_rule("CH -> B");
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
+        assert!(PolymerTool::parse_rule("CHH -> B").is_err());
+        assert!(PolymerTool::parse_rule("CH ->").is_err());
     }
 
     #[test]
@@ -177,30 +234,65 @@ mod tests {
     }
 
     #[test]
-    fn test_get_template_and_rules() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+    fn test_try_from() {
+        let input = EXAMPLES[0].get_lines();
+
+        let mut expected = PolymerTool::new();
+
+        expected.add_template(&input[0]);
+
+        input[2..]
+            .iter()
+            .for_each(|rule| expected.add_rule(rule).unwrap());
 
-        let expected_template = TEST_DATA[0].to_string();
-        let expected_rules = TEST_DATA[2..].iter().map(|s| s.to_string()).collect();
+        let result = PolymerTool::try_from(&input[..]);
 
-        let expected = (expected_template, expected_rules);
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_try_from_error() {
+        let expected = vec![
+            "2: expected a single template line",
+            "expected a polymer template",
+            "4: expected 'AB -> C'",
+        ];
+
+        let result: Vec<String> = [
+            vec!["NN", "NC", "", "NN -> C"],
+            vec![],
+            vec!["NN", "", "NN -> C", "CB => H"],
+        ]
+        .iter()
+        .map(|lines| {
+            let input: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
 
-        let result = get_template_and_rules(&input);
+            PolymerTool::try_from(&input[..]).unwrap_err().to_string()
+        })
+        .collect();
 
         assert_eq!(result, expected);
     }
 
-    fn get_test_tool() -> PolymerTool {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+    #[test]
+    fn test_try_from_incomplete_rules() {
+        let input: Vec<String> = EXAMPLES[0]
+            .input
+            .lines()
+            .filter(|&line| line != "CH -> B")
+            .map(String::from)
+            .collect();
 
-        let (template, rules) = get_template_and_rules(&input);
+        let expected = "expected a rule for the pair 'CH'";
 
-        let mut test_tool = PolymerTool::new();
+        let result = PolymerTool::try_from(&input[..]).unwrap_err().to_string();
 
-        test_tool.add_template(&template);
+        assert_eq!(result, expected);
+    }
 
-        rules.iter().for_each(|rule| test_tool.add_rule(rule));
+    fn get_test_tool() -> PolymerTool {
+        let input = EXAMPLES[0].get_lines();
 
-        test_tool
+        PolymerTool::try_from(&input[..]).unwrap()
     }
 }
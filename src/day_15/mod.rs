@@ -1,20 +1,54 @@
 mod cave_map;
 
-use crate::util::file_reader::to_string_vector;
-use cave_map::CaveMap;
-
-pub fn run_day_15() {
-    let file_input = to_string_vector("inputs/day_15.txt").unwrap();
-
-    let mut cave_map = CaveMap::from(file_input.as_slice());
-
-    let part_1_result = cave_map.get_lowest_total_risk_level_to_exit();
-
-    println!("Day 15 Part 1: {}", part_1_result.unwrap());
-
-    cave_map.tile_repeat_by_five();
-
-    let part_2_result = cave_map.get_lowest_total_risk_level_to_exit();
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use cave_map::CaveMap;
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581",
+    part_one: Some("40"),
+    part_two: Some("315"),
+}];
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = CaveMap;
+
+    fn parse(&self, input: &[String]) -> Result<CaveMap, ParseError> {
+        CaveMap::try_from(input)
+    }
+
+    fn part_one(&self, cave_map: &CaveMap) -> Result<Answer, String> {
+        get_lowest_total_risk_level_to_exit(cave_map)
+    }
+
+    fn part_two(&self, cave_map: &CaveMap) -> Result<Answer, String> {
+        let mut cave_map = cave_map.clone();
+
+        cave_map.tile_repeat_by_five();
+
+        get_lowest_total_risk_level_to_exit(&cave_map)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+}
 
-    println!("Day 15 Part 2: {}", part_2_result.unwrap());
+fn get_lowest_total_risk_level_to_exit(cave_map: &CaveMap) -> Result<Answer, String> {
+    cave_map
+        .get_lowest_total_risk_level_to_exit()
+        .map(Answer::from)
+        .ok_or_else(|| String::from("There is no path to the exit!"))
 }
@@ -1,18 +1,25 @@
 use super::hex_converter::hex_str_to_binary_string;
 use super::packet::Packet;
+use crate::util::parse_error::ParseError;
+use std::str::FromStr;
 
 #[derive(Debug, PartialEq)]
 pub struct BuoyancyInterchangeTransmissionSystem {
     pub outer_packet: Packet,
 }
 
-impl BuoyancyInterchangeTransmissionSystem {
-    pub fn new(input: &str) -> BuoyancyInterchangeTransmissionSystem {
-        BuoyancyInterchangeTransmissionSystem {
-            outer_packet: Packet::from(hex_str_to_binary_string(input).as_str()),
-        }
+impl FromStr for BuoyancyInterchangeTransmissionSystem {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<BuoyancyInterchangeTransmissionSystem, ParseError> {
+        let outer_packet = Packet::try_from(hex_str_to_binary_string(input)?.as_str())
+            .map_err(|error| ParseError::new(input, &error.message))?;
+
+        Ok(BuoyancyInterchangeTransmissionSystem { outer_packet })
     }
+}
 
+impl BuoyancyInterchangeTransmissionSystem {
     pub fn get_total_version_sum(&self) -> usize {
         self.outer_packet.get_total_version_sum()
     }
@@ -50,7 +57,11 @@ mod tests {
 
         let result: Vec<usize> = TEST_DATA
             .iter()
-            .map(|s| BuoyancyInterchangeTransmissionSystem::new(s).get_total_version_sum())
+            .map(|s| {
+                s.parse::<BuoyancyInterchangeTransmissionSystem>()
+                    .unwrap()
+                    .get_total_version_sum()
+            })
             .collect();
 
         assert_eq!(result, expected);
@@ -62,7 +73,30 @@ mod tests {
 
         let result: Vec<usize> = TEST_DATA_LARGE
             .iter()
-            .map(|s| BuoyancyInterchangeTransmissionSystem::new(s).get_value())
+            .map(|s| {
+                s.parse::<BuoyancyInterchangeTransmissionSystem>()
+                    .unwrap()
+                    .get_value()
+            })
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        let expected = vec![
+            "expected a hexadecimal digit, but got 'x'",
+            "transmission ended in the middle of a packet",
+        ];
+
+        let result: Vec<String> = ["8A0x4A801A8002F478", "8A004A80"]
+            .iter()
+            .map(|s| {
+                s.parse::<BuoyancyInterchangeTransmissionSystem>()
+                    .unwrap_err()
+                    .message
+            })
             .collect();
 
         assert_eq!(result, expected);
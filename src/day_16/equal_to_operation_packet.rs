@@ -1,4 +1,5 @@
 use super::operation_packet::OperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct EqualToOperationPacket {
@@ -39,13 +40,22 @@ impl EqualToOperationPacket {
     }
 }
 
-impl From<&str> for EqualToOperationPacket {
-    fn from(input: &str) -> EqualToOperationPacket {
-        let operation = OperationPacket::from(input);
+impl TryFrom<&str> for EqualToOperationPacket {
+    type Error = ParseError;
 
-        EqualToOperationPacket {
-            operation_packet: operation,
+    fn try_from(input: &str) -> Result<EqualToOperationPacket, ParseError> {
+        let operation = OperationPacket::try_from(input)?;
+
+        if operation.get_subpackets().len() != 2 {
+            return Err(ParseError::new(
+                input,
+                "expected exactly 2 subpackets to compare",
+            ));
         }
+
+        Ok(EqualToOperationPacket {
+            operation_packet: operation,
+        })
     }
 }
 
@@ -59,7 +69,9 @@ mod tests {
     fn get_value() {
         let expected = 0;
 
-        let result = EqualToOperationPacket::from(TEST_DATA).get_value();
+        let result = EqualToOperationPacket::try_from(TEST_DATA)
+            .unwrap()
+            .get_value();
 
         assert_eq!(result, expected);
     }
@@ -1,4 +1,5 @@
 use super::operation_packet::OperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct GreaterThanOperationPacket {
@@ -39,13 +40,22 @@ impl GreaterThanOperationPacket {
     }
 }
 
-impl From<&str> for GreaterThanOperationPacket {
-    fn from(input: &str) -> GreaterThanOperationPacket {
-        let operation = OperationPacket::from(input);
+impl TryFrom<&str> for GreaterThanOperationPacket {
+    type Error = ParseError;
 
-        GreaterThanOperationPacket {
-            operation_packet: operation,
+    fn try_from(input: &str) -> Result<GreaterThanOperationPacket, ParseError> {
+        let operation = OperationPacket::try_from(input)?;
+
+        if operation.get_subpackets().len() != 2 {
+            return Err(ParseError::new(
+                input,
+                "expected exactly 2 subpackets to compare",
+            ));
         }
+
+        Ok(GreaterThanOperationPacket {
+            operation_packet: operation,
+        })
     }
 }
 
@@ -59,7 +69,9 @@ mod tests {
     fn get_value() {
         let expected = 0;
 
-        let result = GreaterThanOperationPacket::from(TEST_DATA).get_value();
+        let result = GreaterThanOperationPacket::try_from(TEST_DATA)
+            .unwrap()
+            .get_value();
 
         assert_eq!(result, expected);
     }
@@ -1,11 +1,35 @@
-pub fn hex_str_to_binary_string(input: &str) -> String {
+use crate::util::parse_error::ParseError;
+
+pub fn hex_str_to_binary_string(input: &str) -> Result<String, ParseError> {
     input
         .chars()
-        .map(|hex_char| hex_char_to_binary_string(hex_char))
+        .enumerate()
+        .map(|(index, hex_char)| {
+            hex_char_to_binary_string(hex_char).ok_or_else(|| {
+                ParseError::new(
+                    input,
+                    &format!("expected a hexadecimal digit, but got '{}'", hex_char),
+                )
+                .at_column(index + 1)
+            })
+        })
         .collect()
 }
 
-fn hex_char_to_binary_string(hex_char: char) -> String {
+pub fn get_bits(input: &str, start: usize, end: usize) -> Result<&str, ParseError> {
+    input
+        .get(start..end)
+        .ok_or_else(|| ParseError::new(input, "transmission ended in the middle of a packet"))
+}
+
+pub fn get_bits_as_number(input: &str, start: usize, end: usize) -> Result<usize, ParseError> {
+    let bits = get_bits(input, start, end)?;
+
+    usize::from_str_radix(bits, 2)
+        .map_err(|_| ParseError::new(bits, &format!("expected binary digits, but got '{}'", bits)))
+}
+
+fn hex_char_to_binary_string(hex_char: char) -> Option<String> {
     let binary_string = match hex_char {
         '0' => "0000",
         '1' => "0001",
@@ -23,10 +47,10 @@ fn hex_char_to_binary_string(hex_char: char) -> String {
         'D' => "1101",
         'E' => "1110",
         'F' => "1111",
-        _ => panic!("Could not convert hex char: {}", hex_char),
+        _ => return None,
     };
 
-    binary_string.to_string()
+    Some(binary_string.to_string())
 }
 
 #[cfg(test)]
@@ -45,9 +69,25 @@ mod tests {
 
         let result: Vec<String> = TEST_DATA
             .iter()
-            .map(|hex_str| hex_str_to_binary_string(hex_str))
+            .map(|hex_str| hex_str_to_binary_string(hex_str).unwrap())
             .collect();
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_hex_str_to_binary_string_error() {
+        let expected = "1:3: expected a hexadecimal digit, but got 'G'";
+
+        let result =
+            hex_str_to_binary_string("D2GE28").map_err(|error| error.at_line(1).to_string());
+
+        assert_eq!(result, Err(String::from(expected)));
+    }
+
+    #[test]
+    fn test_get_bits_as_number() {
+        assert_eq!(get_bits_as_number("110100", 0, 3), Ok(6));
+        assert!(get_bits_as_number("110100", 3, 9).is_err());
+    }
 }
@@ -1,3 +1,6 @@
+use super::hex_converter::{get_bits, get_bits_as_number};
+use crate::util::parse_error::ParseError;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum LengthId {
     TotalSizeOfSubpackets(usize),
@@ -13,23 +16,21 @@ impl LengthId {
     }
 }
 
-impl From<&str> for LengthId {
-    fn from(input: &str) -> LengthId {
-        let indication = input
-            .chars()
-            .nth(6)
-            .expect(&format!("Couldn't parse length id: {}", input));
-
-        let possible_total_size = usize::from_str_radix(&input[7..22], 2)
-            .expect(&format!("Couldn't parse total size: {}", input));
-
-        let possible_total_number = usize::from_str_radix(&input[7..18], 2)
-            .expect(&format!("Couldn't parse total number: {}", input));
+impl TryFrom<&str> for LengthId {
+    type Error = ParseError;
 
-        match indication {
-            '0' => LengthId::TotalSizeOfSubpackets(possible_total_size),
-            '1' => LengthId::NumberOfSubpackets(possible_total_number),
-            _ => panic!("Unknown length id: {}", input),
+    fn try_from(input: &str) -> Result<LengthId, ParseError> {
+        match get_bits(input, 6, 7)? {
+            "0" => Ok(LengthId::TotalSizeOfSubpackets(get_bits_as_number(
+                input, 7, 22,
+            )?)),
+            "1" => Ok(LengthId::NumberOfSubpackets(get_bits_as_number(
+                input, 7, 18,
+            )?)),
+            indication => Err(ParseError::new(
+                input,
+                &format!("expected a length type id, but got '{}'", indication),
+            )),
         }
     }
 }
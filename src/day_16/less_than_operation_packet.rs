@@ -1,4 +1,5 @@
 use super::operation_packet::OperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct LessThanOperationPacket {
@@ -39,13 +40,22 @@ impl LessThanOperationPacket {
     }
 }
 
-impl From<&str> for LessThanOperationPacket {
-    fn from(input: &str) -> LessThanOperationPacket {
-        let operation = OperationPacket::from(input);
+impl TryFrom<&str> for LessThanOperationPacket {
+    type Error = ParseError;
 
-        LessThanOperationPacket {
-            operation_packet: operation,
+    fn try_from(input: &str) -> Result<LessThanOperationPacket, ParseError> {
+        let operation = OperationPacket::try_from(input)?;
+
+        if operation.get_subpackets().len() != 2 {
+            return Err(ParseError::new(
+                input,
+                "expected exactly 2 subpackets to compare",
+            ));
         }
+
+        Ok(LessThanOperationPacket {
+            operation_packet: operation,
+        })
     }
 }
 
@@ -59,7 +69,9 @@ mod tests {
     fn get_value() {
         let expected = 1;
 
-        let result = LessThanOperationPacket::from(TEST_DATA).get_value();
+        let result = LessThanOperationPacket::try_from(TEST_DATA)
+            .unwrap()
+            .get_value();
 
         assert_eq!(result, expected);
     }
@@ -1,4 +1,5 @@
 use super::packet_header::PacketHeader;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct LiteralValuePacket {
@@ -21,9 +22,11 @@ impl LiteralValuePacket {
     }
 }
 
-impl From<&str> for LiteralValuePacket {
-    fn from(input: &str) -> LiteralValuePacket {
-        let header = PacketHeader::from(input);
+impl TryFrom<&str> for LiteralValuePacket {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<LiteralValuePacket, ParseError> {
+        let header = PacketHeader::try_from(input)?;
 
         let mut size = 6;
         let mut value = 0;
@@ -46,11 +49,18 @@ impl From<&str> for LiteralValuePacket {
             size += 1;
         }
 
-        LiteralValuePacket {
+        if !stop || (size - 6) % 5 != 0 {
+            return Err(ParseError::new(
+                input,
+                "transmission ended in the middle of a literal value",
+            ));
+        }
+
+        Ok(LiteralValuePacket {
             header,
             value,
             size,
-        }
+        })
     }
 }
 
@@ -63,13 +73,14 @@ mod tests {
     #[test]
     fn test_literal_value_from() {
         let expected = LiteralValuePacket {
-            header: PacketHeader::from("110100"),
+            header: PacketHeader::try_from("110100").unwrap(),
             value: 2021,
             size: 21,
         };
 
-        let result = LiteralValuePacket::from(TEST_DATA);
+        let result = LiteralValuePacket::try_from(TEST_DATA);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
+        assert!(LiteralValuePacket::try_from(&TEST_DATA[..15]).is_err());
     }
 }
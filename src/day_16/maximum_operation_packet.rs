@@ -1,4 +1,5 @@
 use super::operation_packet::OperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct MaximumOperationPacket {
@@ -24,13 +25,15 @@ impl MaximumOperationPacket {
     }
 }
 
-impl From<&str> for MaximumOperationPacket {
-    fn from(input: &str) -> MaximumOperationPacket {
-        let operation = OperationPacket::from(input);
+impl TryFrom<&str> for MaximumOperationPacket {
+    type Error = ParseError;
 
-        MaximumOperationPacket {
+    fn try_from(input: &str) -> Result<MaximumOperationPacket, ParseError> {
+        let operation = OperationPacket::try_from(input)?;
+
+        Ok(MaximumOperationPacket {
             operation_packet: operation,
-        }
+        })
     }
 }
 
@@ -44,7 +47,9 @@ mod tests {
     fn get_value() {
         let expected = 9;
 
-        let result = MaximumOperationPacket::from(TEST_DATA).get_value();
+        let result = MaximumOperationPacket::try_from(TEST_DATA)
+            .unwrap()
+            .get_value();
 
         assert_eq!(result, expected);
     }
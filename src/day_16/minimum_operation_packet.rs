@@ -1,4 +1,5 @@
 use super::operation_packet::OperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct MinimumOperationPacket {
@@ -24,13 +25,15 @@ impl MinimumOperationPacket {
     }
 }
 
-impl From<&str> for MinimumOperationPacket {
-    fn from(input: &str) -> MinimumOperationPacket {
-        let operation = OperationPacket::from(input);
+impl TryFrom<&str> for MinimumOperationPacket {
+    type Error = ParseError;
 
-        MinimumOperationPacket {
+    fn try_from(input: &str) -> Result<MinimumOperationPacket, ParseError> {
+        let operation = OperationPacket::try_from(input)?;
+
+        Ok(MinimumOperationPacket {
             operation_packet: operation,
-        }
+        })
     }
 }
 
@@ -44,7 +47,9 @@ mod tests {
     fn get_value() {
         let expected = 7;
 
-        let result = MinimumOperationPacket::from(TEST_DATA).get_value();
+        let result = MinimumOperationPacket::try_from(TEST_DATA)
+            .unwrap()
+            .get_value();
 
         assert_eq!(result, expected);
     }
@@ -13,19 +13,99 @@ mod packet_header;
 mod product_operation_packet;
 mod sum_operation_packet;
 
-use crate::util::file_reader::to_string_vector;
-use buoyancy_interchange_transmission_system::BuoyancyInterchangeTransmissionSystem;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::file_reader::get_single_line;
+use crate::util::parse_error::ParseError;
+pub use buoyancy_interchange_transmission_system::BuoyancyInterchangeTransmissionSystem;
 
-pub fn run_day_16() {
-    let file_input = to_string_vector("inputs/day_16.txt").unwrap();
+const EXAMPLES: [Example; 12] = [
+    Example {
+        input: "8A004A801A8002F478",
+        part_one: Some("16"),
+        part_two: None,
+    },
+    Example {
+        input: "620080001611562C8802118E34",
+        part_one: Some("12"),
+        part_two: None,
+    },
+    Example {
+        input: "C0015000016115A2E0802F182340",
+        part_one: Some("23"),
+        part_two: None,
+    },
+    Example {
+        input: "A0016C880162017C3686B18A3D4780",
+        part_one: Some("31"),
+        part_two: None,
+    },
+    Example {
+        input: "C200B40A82",
+        part_one: None,
+        part_two: Some("3"),
+    },
+    Example {
+        input: "04005AC33890",
+        part_one: None,
+        part_two: Some("54"),
+    },
+    Example {
+        input: "880086C3E88112",
+        part_one: None,
+        part_two: Some("7"),
+    },
+    Example {
+        input: "CE00C43D881120",
+        part_one: None,
+        part_two: Some("9"),
+    },
+    Example {
+        input: "D8005AC2A8F0",
+        part_one: None,
+        part_two: Some("1"),
+    },
+    Example {
+        input: "F600BC2D8F",
+        part_one: None,
+        part_two: Some("0"),
+    },
+    Example {
+        input: "9C005AC2F8F0",
+        part_one: None,
+        part_two: Some("0"),
+    },
+    Example {
+        input: "9C0141080250320F1802104A08",
+        part_one: None,
+        part_two: Some("1"),
+    },
+];
 
-    let bits = BuoyancyInterchangeTransmissionSystem::new(
-        &file_input.get(0).expect("Day 16 file was empty!"),
-    );
+pub struct Day16;
 
-    let part_1_result = bits.get_total_version_sum();
-    let part_2_result = bits.get_value();
+impl Solution for Day16 {
+    type Input = BuoyancyInterchangeTransmissionSystem;
 
-    println!("Day 16 Part 1: {}", part_1_result);
-    println!("Day 16 Part 2: {}", part_2_result);
+    fn parse(&self, input: &[String]) -> Result<BuoyancyInterchangeTransmissionSystem, ParseError> {
+        get_single_line(input)
+            .and_then(|line| line.parse().map_err(|error: ParseError| error.at_line(1)))
+    }
+
+    fn part_one(
+        &self,
+        transmission_system: &BuoyancyInterchangeTransmissionSystem,
+    ) -> Result<Answer, String> {
+        Ok(transmission_system.get_total_version_sum().into())
+    }
+
+    fn part_two(
+        &self,
+        transmission_system: &BuoyancyInterchangeTransmissionSystem,
+    ) -> Result<Answer, String> {
+        Ok(transmission_system.get_value().into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -1,6 +1,7 @@
 use super::length_id::LengthId;
 use super::packet::Packet;
 use super::packet_header::PacketHeader;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct OperationPacket {
@@ -31,43 +32,55 @@ impl OperationPacket {
         &self.subpackets
     }
 
-    pub fn build_subpackets(input: &str) -> Vec<Packet> {
-        let length_id = LengthId::from(input);
+    pub fn build_subpackets(input: &str) -> Result<Vec<Packet>, ParseError> {
+        let length_id = LengthId::try_from(input)?;
 
         let starting_index = 6 + length_id.get_size();
 
+        let subpackets_input = input.get(starting_index..).unwrap_or_default();
+
         match length_id {
             LengthId::TotalSizeOfSubpackets(total_size) => {
-                Self::build_subpackets_by_total_size(total_size, &input[starting_index..])
+                Self::build_subpackets_by_total_size(total_size, subpackets_input)
             }
             LengthId::NumberOfSubpackets(count) => {
-                Self::build_subpackets_by_counting(count, &input[starting_index..])
+                Self::build_subpackets_by_counting(count, subpackets_input)
             }
         }
     }
 
-    fn build_subpackets_by_total_size(total_size: usize, input: &str) -> Vec<Packet> {
+    fn build_subpackets_by_total_size(
+        total_size: usize,
+        input: &str,
+    ) -> Result<Vec<Packet>, ParseError> {
         let mut result = Vec::new();
         let mut running_size = 0;
 
-        while running_size != total_size {
-            let subpacket = Packet::from(&input[running_size..]);
+        while running_size < total_size {
+            let subpacket = Packet::try_from(&input[running_size..])?;
 
             running_size += subpacket.get_size();
 
             result.push(subpacket);
         }
 
-        result
+        if running_size != total_size {
+            return Err(ParseError::new(
+                input,
+                &format!("expected subpackets totalling {} bits", total_size),
+            ));
+        }
+
+        Ok(result)
     }
 
-    fn build_subpackets_by_counting(count: usize, input: &str) -> Vec<Packet> {
+    fn build_subpackets_by_counting(count: usize, input: &str) -> Result<Vec<Packet>, ParseError> {
         let mut result = Vec::new();
         let mut running_count = 0;
         let mut running_size = 0;
 
         while running_count != count {
-            let subpacket = Packet::from(&input[running_size..]);
+            let subpacket = Packet::try_from(&input[running_size..])?;
 
             running_count += 1;
             running_size += subpacket.get_size();
@@ -75,32 +88,34 @@ impl OperationPacket {
             result.push(subpacket);
         }
 
-        result
+        Ok(result)
     }
 }
 
-impl From<&str> for OperationPacket {
-    fn from(input: &str) -> OperationPacket {
-        let header = PacketHeader::from(input);
+impl TryFrom<&str> for OperationPacket {
+    type Error = ParseError;
 
-        let length_id = LengthId::from(input);
+    fn try_from(input: &str) -> Result<OperationPacket, ParseError> {
+        let header = PacketHeader::try_from(input)?;
+
+        let length_id = LengthId::try_from(input)?;
         let mut size = 6;
 
         size += length_id.get_size();
 
-        let subpackets = OperationPacket::build_subpackets(input);
+        let subpackets = OperationPacket::build_subpackets(input)?;
 
         size += subpackets
             .iter()
             .map(|subpacket| subpacket.get_size())
             .sum::<usize>();
 
-        OperationPacket {
+        Ok(OperationPacket {
             header,
             length_id,
             subpackets,
             size,
-        }
+        })
     }
 }
 
@@ -116,39 +131,46 @@ mod tests {
 
     #[test]
     fn test_operation_build_subpackets() {
-        let expected_subpacket_1 = Packet::Literal(LiteralValuePacket::from("11010001010"));
-        let expected_subpacket_2 = Packet::Literal(LiteralValuePacket::from("0101001000100100"));
+        let expected_subpacket_1 =
+            Packet::Literal(LiteralValuePacket::try_from("11010001010").unwrap());
+        let expected_subpacket_2 =
+            Packet::Literal(LiteralValuePacket::try_from("0101001000100100").unwrap());
         let expected = vec![expected_subpacket_1, expected_subpacket_2];
 
         let result = OperationPacket::build_subpackets(TEST_DATA[0]);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
     fn test_operation_size_from() {
-        let expected_subpacket_1 = Packet::Literal(LiteralValuePacket::from("11010001010"));
-        let expected_subpacket_2 = Packet::Literal(LiteralValuePacket::from("0101001000100100"));
+        let expected_subpacket_1 =
+            Packet::Literal(LiteralValuePacket::try_from("11010001010").unwrap());
+        let expected_subpacket_2 =
+            Packet::Literal(LiteralValuePacket::try_from("0101001000100100").unwrap());
         let expected = OperationPacket {
-            header: PacketHeader::from(TEST_DATA[0]),
-            length_id: LengthId::from(TEST_DATA[0]),
+            header: PacketHeader::try_from(TEST_DATA[0]).unwrap(),
+            length_id: LengthId::try_from(TEST_DATA[0]).unwrap(),
             subpackets: vec![expected_subpacket_1, expected_subpacket_2],
             size: 49,
         };
 
-        let result = OperationPacket::from(TEST_DATA[0]);
+        let result = OperationPacket::try_from(TEST_DATA[0]);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
     fn test_operation_count_from() {
-        let expected_subpacket_1 = Packet::Literal(LiteralValuePacket::from("01010000001"));
-        let expected_subpacket_2 = Packet::Literal(LiteralValuePacket::from("10010000010"));
-        let expected_subpacket_3 = Packet::Literal(LiteralValuePacket::from("00110000011"));
+        let expected_subpacket_1 =
+            Packet::Literal(LiteralValuePacket::try_from("01010000001").unwrap());
+        let expected_subpacket_2 =
+            Packet::Literal(LiteralValuePacket::try_from("10010000010").unwrap());
+        let expected_subpacket_3 =
+            Packet::Literal(LiteralValuePacket::try_from("00110000011").unwrap());
         let expected = OperationPacket {
-            header: PacketHeader::from(TEST_DATA[1]),
-            length_id: LengthId::from(TEST_DATA[1]),
+            header: PacketHeader::try_from(TEST_DATA[1]).unwrap(),
+            length_id: LengthId::try_from(TEST_DATA[1]).unwrap(),
             subpackets: vec![
                 expected_subpacket_1,
                 expected_subpacket_2,
@@ -157,8 +179,18 @@ mod tests {
             size: 51,
         };
 
-        let result = OperationPacket::from(TEST_DATA[1]);
+        let result = OperationPacket::try_from(TEST_DATA[1]);
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_operation_truncated() {
+        let expected = "transmission ended in the middle of a packet";
+
+        let result =
+            OperationPacket::try_from(&TEST_DATA[1][..40]).map_err(|error| error.to_string());
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Err(String::from(expected)));
     }
 }
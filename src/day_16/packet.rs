@@ -1,11 +1,13 @@
 use super::equal_to_operation_packet::EqualToOperationPacket;
 use super::greater_than_operation_packet::GreaterThanOperationPacket;
+use super::hex_converter::get_bits_as_number;
 use super::less_than_operation_packet::LessThanOperationPacket;
 use super::literal_packet::LiteralValuePacket;
 use super::maximum_operation_packet::MaximumOperationPacket;
 use super::minimum_operation_packet::MinimumOperationPacket;
 use super::product_operation_packet::ProductOperationPacket;
 use super::sum_operation_packet::SumOperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub enum Packet {
@@ -60,22 +62,22 @@ impl Packet {
     }
 }
 
-impl From<&str> for Packet {
-    fn from(input: &str) -> Packet {
-        let type_id =
-            u8::from_str_radix(&input[3..6], 2).expect(&format!("Couldn't parse type: {}", input));
-
-        match type_id {
-            0 => Packet::SumOperation(SumOperationPacket::from(input)),
-            1 => Packet::ProductOperation(ProductOperationPacket::from(input)),
-            2 => Packet::MinimumOperation(MinimumOperationPacket::from(input)),
-            3 => Packet::MaximumOperation(MaximumOperationPacket::from(input)),
-            4 => Packet::Literal(LiteralValuePacket::from(input)),
-            5 => Packet::GreaterThanOperation(GreaterThanOperationPacket::from(input)),
-            6 => Packet::LessThanOperation(LessThanOperationPacket::from(input)),
-            7 => Packet::EqualToOperation(EqualToOperationPacket::from(input)),
-            _ => unimplemented!("Packet Type Id not implemented: {}", type_id),
-        }
+impl TryFrom<&str> for Packet {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Packet, ParseError> {
+        let type_id = get_bits_as_number(input, 3, 6)?;
+
+        Ok(match type_id {
+            0 => Packet::SumOperation(SumOperationPacket::try_from(input)?),
+            1 => Packet::ProductOperation(ProductOperationPacket::try_from(input)?),
+            2 => Packet::MinimumOperation(MinimumOperationPacket::try_from(input)?),
+            3 => Packet::MaximumOperation(MaximumOperationPacket::try_from(input)?),
+            4 => Packet::Literal(LiteralValuePacket::try_from(input)?),
+            5 => Packet::GreaterThanOperation(GreaterThanOperationPacket::try_from(input)?),
+            6 => Packet::LessThanOperation(LessThanOperationPacket::try_from(input)?),
+            _ => Packet::EqualToOperation(EqualToOperationPacket::try_from(input)?),
+        })
     }
 }
 
@@ -102,20 +104,21 @@ mod tests {
 
     #[test]
     fn test_packet_literal_from() {
-        let expected = Packet::Literal(LiteralValuePacket::from(TEST_DATA[0]));
+        let expected = Packet::Literal(LiteralValuePacket::try_from(TEST_DATA[0]).unwrap());
 
-        let result = Packet::from(TEST_DATA[0]);
+        let result = Packet::try_from(TEST_DATA[0]);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
     fn test_packet_operation_from() {
-        let expected = Packet::LessThanOperation(LessThanOperationPacket::from(TEST_DATA[1]));
+        let expected =
+            Packet::LessThanOperation(LessThanOperationPacket::try_from(TEST_DATA[1]).unwrap());
 
-        let result = Packet::from(TEST_DATA[1]);
+        let result = Packet::try_from(TEST_DATA[1]);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
@@ -124,7 +127,7 @@ mod tests {
 
         let result: Vec<usize> = TEST_DATA
             .iter()
-            .map(|s| Packet::from(*s).get_total_version_sum())
+            .map(|s| Packet::try_from(*s).unwrap().get_total_version_sum())
             .collect();
 
         assert_eq!(result, expected);
@@ -136,7 +139,7 @@ mod tests {
 
         let result: Vec<usize> = TEST_OPERATION_DATA
             .iter()
-            .map(|s| Packet::from(*s).get_value())
+            .map(|s| Packet::try_from(*s).unwrap().get_value())
             .collect();
 
         assert_eq!(result, expected);
@@ -1,3 +1,6 @@
+use super::hex_converter::get_bits_as_number;
+use crate::util::parse_error::ParseError;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct PacketHeader {
     version: u8,
@@ -10,14 +13,14 @@ impl PacketHeader {
     }
 }
 
-impl From<&str> for PacketHeader {
-    fn from(input: &str) -> PacketHeader {
-        let version = u8::from_str_radix(&input[0..3], 2)
-            .expect(&format!("Couldn't parse version: {}", input));
+impl TryFrom<&str> for PacketHeader {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<PacketHeader, ParseError> {
+        let version = get_bits_as_number(input, 0, 3)? as u8;
 
-        let type_id = u8::from_str_radix(&input[3..6], 2)
-            .expect(&format!("Couldn't parse type id: {}", input));
+        let type_id = get_bits_as_number(input, 3, 6)? as u8;
 
-        PacketHeader { version, type_id }
+        Ok(PacketHeader { version, type_id })
     }
 }
@@ -1,4 +1,5 @@
 use super::operation_packet::OperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct ProductOperationPacket {
@@ -23,13 +24,15 @@ impl ProductOperationPacket {
     }
 }
 
-impl From<&str> for ProductOperationPacket {
-    fn from(input: &str) -> ProductOperationPacket {
-        let operation = OperationPacket::from(input);
+impl TryFrom<&str> for ProductOperationPacket {
+    type Error = ParseError;
 
-        ProductOperationPacket {
+    fn try_from(input: &str) -> Result<ProductOperationPacket, ParseError> {
+        let operation = OperationPacket::try_from(input)?;
+
+        Ok(ProductOperationPacket {
             operation_packet: operation,
-        }
+        })
     }
 }
 
@@ -43,7 +46,9 @@ mod tests {
     fn get_value() {
         let expected = 54;
 
-        let result = ProductOperationPacket::from(TEST_DATA).get_value();
+        let result = ProductOperationPacket::try_from(TEST_DATA)
+            .unwrap()
+            .get_value();
 
         assert_eq!(result, expected);
     }
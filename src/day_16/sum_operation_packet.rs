@@ -1,4 +1,5 @@
 use super::operation_packet::OperationPacket;
+use crate::util::parse_error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct SumOperationPacket {
@@ -23,13 +24,15 @@ impl SumOperationPacket {
     }
 }
 
-impl From<&str> for SumOperationPacket {
-    fn from(input: &str) -> SumOperationPacket {
-        let operation = OperationPacket::from(input);
+impl TryFrom<&str> for SumOperationPacket {
+    type Error = ParseError;
 
-        SumOperationPacket {
+    fn try_from(input: &str) -> Result<SumOperationPacket, ParseError> {
+        let operation = OperationPacket::try_from(input)?;
+
+        Ok(SumOperationPacket {
             operation_packet: operation,
-        }
+        })
     }
 }
 
@@ -43,7 +46,7 @@ mod tests {
     fn get_value() {
         let expected = 3;
 
-        let result = SumOperationPacket::from(TEST_DATA).get_value();
+        let result = SumOperationPacket::try_from(TEST_DATA).unwrap().get_value();
 
         assert_eq!(result, expected);
     }
@@ -1,8 +1,51 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod probe_launcher;
 
-pub fn run_day_17() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_17.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Part, Solution};
+use crate::util::file_reader::get_single_line;
+use crate::util::parse_error::ParseError;
+pub use probe_launcher::{ProbeLauncher, TargetArea};
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "target area: x=20..30, y=-10..-5",
+    part_one: Some("45"),
+    part_two: Some("112"),
+}];
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = ProbeLauncher;
+
+    fn parse(&self, input: &[String]) -> Result<ProbeLauncher, ParseError> {
+        get_single_line(input)
+            .and_then(|line| line.parse().map_err(|error: ParseError| error.at_line(1)))
+            .map(ProbeLauncher::new)
+    }
+
+    fn part_one(&self, launcher: &ProbeLauncher) -> Result<Answer, String> {
+        launcher
+            .get_highest_apex()
+            .map(Answer::from)
+            .ok_or_else(|| String::from("No initial velocity hits the target area!"))
+    }
+
+    fn part_two(&self, launcher: &ProbeLauncher) -> Result<Answer, String> {
+        Ok(launcher.get_number_of_valid_initial_velocities().into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+
+    fn diagram(&self, launcher: &ProbeLauncher, part: Part) -> Option<Result<String, String>> {
+        match part {
+            Part::One => Some(
+                launcher
+                    .get_highest_initial_velocity()
+                    .map(|velocity| launcher.get_trajectory_diagram(velocity))
+                    .ok_or_else(|| String::from("No initial velocity hits the target area!")),
+            ),
+            Part::Two => None,
+        }
+    }
 }
@@ -0,0 +1,328 @@
+use crate::util::parse_error::{parse_number, ParseError};
+use crate::util::point_2d::Point2d;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+const TARGET_AREA_FORMAT: &str = "target area: x=min..max, y=min..max";
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct TargetArea {
+    min: Point2d<i32>,
+    max: Point2d<i32>,
+}
+
+impl TargetArea {
+    pub fn new(min: Point2d<i32>, max: Point2d<i32>) -> TargetArea {
+        TargetArea { min, max }
+    }
+
+    pub fn contains(&self, point: &Point2d<i32>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    fn is_passed_by(&self, position: &Point2d<i32>, velocity: &Point2d<i32>) -> bool {
+        let is_past_x = match velocity.x.cmp(&0) {
+            Ordering::Greater => self.max.x < position.x,
+            Ordering::Less => position.x < self.min.x,
+            Ordering::Equal => !(self.min.x..=self.max.x).contains(&position.x),
+        };
+
+        let is_past_y = (velocity.y < 0) && (position.y < self.min.y);
+
+        is_past_x || is_past_y
+    }
+
+    fn parse_range(range: &str, axis: &str) -> Result<(i32, i32), ParseError> {
+        let (min_str, max_str) = range
+            .strip_prefix(axis)
+            .and_then(|range| range.split_once(".."))
+            .ok_or_else(|| ParseError::expected(range, TARGET_AREA_FORMAT))?;
+
+        let (min, max) = (parse_number(min_str)?, parse_number(max_str)?);
+
+        if max < min {
+            return Err(ParseError::new(
+                range,
+                &format!("expected min <= max, but got {}..{}", min, max),
+            ));
+        }
+
+        Ok((min, max))
+    }
+}
+
+impl FromStr for TargetArea {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<TargetArea, ParseError> {
+        let (x_range, y_range) = input
+            .strip_prefix("target area: ")
+            .and_then(|ranges| ranges.split_once(", "))
+            .ok_or_else(|| ParseError::expected(input, TARGET_AREA_FORMAT))?;
+
+        let (min_x, max_x) = Self::parse_range(x_range, "x=")?;
+        let (min_y, max_y) = Self::parse_range(y_range, "y=")?;
+
+        Ok(TargetArea::new(
+            Point2d::new(min_x, min_y),
+            Point2d::new(max_x, max_y),
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ProbeLauncher {
+    target_area: TargetArea,
+}
+
+impl ProbeLauncher {
+    pub fn new(target_area: TargetArea) -> ProbeLauncher {
+        ProbeLauncher { target_area }
+    }
+
+    pub fn get_trajectory(&self, initial_velocity: Point2d<i32>) -> Vec<Point2d<i32>> {
+        let mut position = Point2d::new(0, 0);
+        let mut velocity = initial_velocity;
+
+        let mut result = vec![position];
+
+        while !self.target_area.contains(&position)
+            && !self.target_area.is_passed_by(&position, &velocity)
+        {
+            position += velocity;
+            velocity = Self::get_next_velocity(&velocity);
+
+            result.push(position);
+        }
+
+        result
+    }
+
+    pub fn get_trajectory_diagram(&self, initial_velocity: Point2d<i32>) -> String {
+        let trajectory: HashSet<Point2d<i32>> =
+            self.get_trajectory(initial_velocity).into_iter().collect();
+
+        let corners = [self.target_area.min, self.target_area.max];
+        let points = trajectory.iter().chain(corners.iter());
+
+        let min_x = points.clone().map(|point| point.x).min().unwrap_or(0);
+        let max_x = points.clone().map(|point| point.x).max().unwrap_or(0);
+        let min_y = points.clone().map(|point| point.y).min().unwrap_or(0);
+        let max_y = points.map(|point| point.y).max().unwrap_or(0);
+
+        let mut result = format!("Initial velocity {}:\n", initial_velocity);
+
+        for y in (min_y..=max_y).rev() {
+            for x in min_x..=max_x {
+                let point = Point2d::new(x, y);
+
+                let c = if point == Point2d::new(0, 0) {
+                    'S'
+                } else if trajectory.contains(&point) {
+                    '#'
+                } else if self.target_area.contains(&point) {
+                    'T'
+                } else {
+                    '.'
+                };
+
+                result.push(c);
+            }
+
+            result.push('\n');
+        }
+
+        result
+    }
+
+    pub fn hits_target_area(&self, initial_velocity: Point2d<i32>) -> bool {
+        self.get_trajectory(initial_velocity)
+            .last()
+            .is_some_and(|position| self.target_area.contains(position))
+    }
+
+    pub fn get_highest_apex(&self) -> Option<i32> {
+        self.get_highest_initial_velocity()
+            .map(|velocity| Self::get_apex(&velocity))
+    }
+
+    pub fn get_highest_initial_velocity(&self) -> Option<Point2d<i32>> {
+        self.get_valid_initial_velocities()
+            .into_iter()
+            .max_by_key(Self::get_apex)
+    }
+
+    pub fn get_number_of_valid_initial_velocities(&self) -> usize {
+        self.get_valid_initial_velocities().len()
+    }
+
+    pub fn get_valid_initial_velocities(&self) -> Vec<Point2d<i32>> {
+        let min_x = self.target_area.min.x.min(0);
+        let max_x = self.target_area.max.x.max(0);
+
+        let max_abs_y = self
+            .target_area
+            .min
+            .y
+            .abs()
+            .max(self.target_area.max.y.abs());
+        let min_y = self.target_area.min.y.min(0);
+
+        let mut result = Vec::new();
+
+        for x in min_x..=max_x {
+            for y in min_y..=max_abs_y {
+                let velocity = Point2d::new(x, y);
+
+                if self.hits_target_area(velocity) {
+                    result.push(velocity);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn get_apex(initial_velocity: &Point2d<i32>) -> i32 {
+        if initial_velocity.y <= 0 {
+            0
+        } else {
+            initial_velocity.y * (initial_velocity.y + 1) / 2
+        }
+    }
+
+    fn get_next_velocity(velocity: &Point2d<i32>) -> Point2d<i32> {
+        let drag = match velocity.x.cmp(&0) {
+            Ordering::Greater => -1,
+            Ordering::Less => 1,
+            Ordering::Equal => 0,
+        };
+
+        Point2d::new(velocity.x + drag, velocity.y - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_17::EXAMPLES;
+
+    #[test]
+    fn test_target_area_from_str() {
+        let expected = TargetArea::new(Point2d::new(20, -10), Point2d::new(30, -5));
+
+        let result = EXAMPLES[0].input.parse();
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_target_area_from_str_error() {
+        let expected = vec![
+            "expected 'target area: x=min..max, y=min..max'",
+            "expected a number, but got '-1O'",
+            "expected min <= max, but got 30..20",
+        ];
+
+        let result: Vec<String> = [
+            "target area: x=20..30 y=-10..-5",
+            "target area: x=20..30, y=-1O..-5",
+            "target area: x=30..20, y=-10..-5",
+        ]
+        .iter()
+        .map(|input| input.parse::<TargetArea>().unwrap_err().message)
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_trajectory() {
+        let launcher = ProbeLauncher::new(EXAMPLES[0].input.parse().unwrap());
+
+        let expected = vec![
+            Point2d::new(0, 0),
+            Point2d::new(7, 2),
+            Point2d::new(13, 3),
+            Point2d::new(18, 3),
+            Point2d::new(22, 2),
+            Point2d::new(25, 0),
+            Point2d::new(27, -3),
+            Point2d::new(28, -7),
+        ];
+
+        let result = launcher.get_trajectory(Point2d::new(7, 2));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_trajectory_diagram() {
+        let launcher = ProbeLauncher::new("target area: x=5..6, y=-3..-2".parse().unwrap());
+
+        let expected = "\
+Initial velocity 3,1:
+...#.#.
+S.....#
+.......
+.....T#
+.....TT
+";
+
+        let result = launcher.get_trajectory_diagram(Point2d::new(3, 1));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_hits_target_area() {
+        let launcher = ProbeLauncher::new(EXAMPLES[0].input.parse().unwrap());
+
+        let expected = vec![true, true, true, false];
+
+        let result: Vec<bool> = vec![
+            Point2d::new(7, 2),
+            Point2d::new(6, 3),
+            Point2d::new(9, 0),
+            Point2d::new(17, -4),
+        ]
+        .into_iter()
+        .map(|velocity| launcher.hits_target_area(velocity))
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_highest_apex() {
+        let launcher = ProbeLauncher::new(EXAMPLES[0].input.parse().unwrap());
+
+        let expected = Some(45);
+
+        let result = launcher.get_highest_apex();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_highest_initial_velocity() {
+        let launcher = ProbeLauncher::new(EXAMPLES[0].input.parse().unwrap());
+
+        let result = launcher.get_highest_initial_velocity();
+
+        assert_eq!(result.map(|velocity| velocity.y), Some(9));
+        assert!(result.is_some_and(|velocity| launcher.hits_target_area(velocity)));
+    }
+
+    #[test]
+    fn test_get_number_of_valid_initial_velocities() {
+        let launcher = ProbeLauncher::new(EXAMPLES[0].input.parse().unwrap());
+
+        let expected = 112;
+
+        let result = launcher.get_number_of_valid_initial_velocities();
+
+        assert_eq!(result, expected);
+    }
+}
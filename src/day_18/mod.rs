@@ -1,8 +1,47 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod snailfish_number;
 
-pub fn run_day_18() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_18.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::{parse_lines, ParseError};
+pub use snailfish_number::{get_largest_magnitude_of_any_two, get_sum, SnailfishNumber};
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+    part_one: Some("4140"),
+    part_two: Some("3993"),
+}];
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<SnailfishNumber>;
+
+    fn parse(&self, input: &[String]) -> Result<Vec<SnailfishNumber>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_one(&self, numbers: &Vec<SnailfishNumber>) -> Result<Answer, String> {
+        get_sum(numbers)
+            .map(|sum| sum.get_magnitude().into())
+            .ok_or_else(|| String::from("Day 18 file was empty!"))
+    }
+
+    fn part_two(&self, numbers: &Vec<SnailfishNumber>) -> Result<Answer, String> {
+        get_largest_magnitude_of_any_two(numbers)
+            .map(Answer::from)
+            .ok_or_else(|| String::from("Day 18 file needs at least two numbers!"))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -0,0 +1,362 @@
+use crate::util::parse_error::ParseError;
+use std::fmt;
+use std::iter::Peekable;
+use std::ops::Add;
+use std::str::{Chars, FromStr};
+
+const EXPLODE_DEPTH: usize = 4;
+const SPLIT_THRESHOLD: u32 = 10;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SnailfishNumber {
+    Regular(u32),
+    Pair(Box<SnailfishNumber>, Box<SnailfishNumber>),
+}
+
+impl SnailfishNumber {
+    pub fn new_pair(left: SnailfishNumber, right: SnailfishNumber) -> SnailfishNumber {
+        SnailfishNumber::Pair(Box::new(left), Box::new(right))
+    }
+
+    pub fn get_magnitude(&self) -> u32 {
+        match self {
+            SnailfishNumber::Regular(value) => *value,
+            SnailfishNumber::Pair(left, right) => {
+                3 * left.get_magnitude() + 2 * right.get_magnitude()
+            }
+        }
+    }
+
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    pub fn explode(&mut self) -> bool {
+        self.explode_at_depth(0).is_some()
+    }
+
+    pub fn split(&mut self) -> bool {
+        match self {
+            SnailfishNumber::Regular(value) if SPLIT_THRESHOLD <= *value => {
+                let left = SnailfishNumber::Regular(*value / 2);
+                let right = SnailfishNumber::Regular(value.div_ceil(2));
+
+                *self = SnailfishNumber::new_pair(left, right);
+
+                true
+            }
+            SnailfishNumber::Regular(_) => false,
+            SnailfishNumber::Pair(left, right) => left.split() || right.split(),
+        }
+    }
+
+    fn explode_at_depth(&mut self, depth: usize) -> Option<(Option<u32>, Option<u32>)> {
+        let (left, right) = match self {
+            SnailfishNumber::Regular(_) => return None,
+            SnailfishNumber::Pair(left, right) => (left, right),
+        };
+
+        if EXPLODE_DEPTH <= depth {
+            if let (SnailfishNumber::Regular(left_value), SnailfishNumber::Regular(right_value)) =
+                (left.as_ref(), right.as_ref())
+            {
+                let carry = (Some(*left_value), Some(*right_value));
+
+                *self = SnailfishNumber::Regular(0);
+
+                return Some(carry);
+            }
+        }
+
+        if let Some((left_carry, right_carry)) = left.explode_at_depth(depth + 1) {
+            if let Some(value) = right_carry {
+                right.add_to_leftmost(value);
+            }
+
+            return Some((left_carry, None));
+        }
+
+        if let Some((left_carry, right_carry)) = right.explode_at_depth(depth + 1) {
+            if let Some(value) = left_carry {
+                left.add_to_rightmost(value);
+            }
+
+            return Some((None, right_carry));
+        }
+
+        None
+    }
+
+    fn add_to_leftmost(&mut self, increase: u32) {
+        match self {
+            SnailfishNumber::Regular(value) => *value += increase,
+            SnailfishNumber::Pair(left, _) => left.add_to_leftmost(increase),
+        }
+    }
+
+    fn add_to_rightmost(&mut self, increase: u32) {
+        match self {
+            SnailfishNumber::Regular(value) => *value += increase,
+            SnailfishNumber::Pair(_, right) => right.add_to_rightmost(increase),
+        }
+    }
+
+    fn parse_from(chars: &mut Peekable<Chars>) -> Result<SnailfishNumber, String> {
+        match chars.peek() {
+            Some('[') => {
+                chars.next();
+
+                let left = Self::parse_from(chars)?;
+
+                Self::expect_char(chars, ',')?;
+
+                let right = Self::parse_from(chars)?;
+
+                Self::expect_char(chars, ']')?;
+
+                Ok(SnailfishNumber::new_pair(left, right))
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let mut value = 0;
+
+                while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                    value = value * 10 + digit;
+
+                    chars.next();
+                }
+
+                Ok(SnailfishNumber::Regular(value))
+            }
+            Some(c) => Err(format!("unexpected character '{}'", c)),
+            None => Err(String::from("unexpected end of input")),
+        }
+    }
+
+    fn expect_char(chars: &mut Peekable<Chars>, expected: char) -> Result<(), String> {
+        match chars.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("expected '{}', but got '{}'", expected, c)),
+            None => Err(format!("expected '{}', but got end of input", expected)),
+        }
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: SnailfishNumber) -> SnailfishNumber {
+        let mut result = SnailfishNumber::new_pair(self, other);
+
+        result.reduce();
+
+        result
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<SnailfishNumber, ParseError> {
+        let mut chars = input.trim().chars().peekable();
+
+        let result = Self::parse_from(&mut chars).and_then(|result| match chars.next() {
+            Some(c) => Err(format!("unexpected trailing character '{}'", c)),
+            None => Ok(result),
+        });
+
+        result.map_err(|message| ParseError::new(input, &message))
+    }
+}
+
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnailfishNumber::Regular(value) => write!(f, "{}", value),
+            SnailfishNumber::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+pub fn get_sum(numbers: &[SnailfishNumber]) -> Option<SnailfishNumber> {
+    numbers.iter().cloned().reduce(|acc, number| acc + number)
+}
+
+pub fn get_largest_magnitude_of_any_two(numbers: &[SnailfishNumber]) -> Option<u32> {
+    let mut result = None;
+
+    for (first_index, first) in numbers.iter().enumerate() {
+        for (second_index, second) in numbers.iter().enumerate() {
+            if first_index == second_index {
+                continue;
+            }
+
+            let magnitude = (first.clone() + second.clone()).get_magnitude();
+
+            result = result.max(Some(magnitude));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_18::EXAMPLES;
+
+    fn parse(input: &str) -> SnailfishNumber {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = SnailfishNumber::new_pair(
+            SnailfishNumber::new_pair(SnailfishNumber::Regular(1), SnailfishNumber::Regular(2)),
+            SnailfishNumber::Regular(13),
+        );
+
+        let result = parse("[[1,2],13]");
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        let expected = vec![
+            "expected ']', but got end of input",
+            "expected ',', but got ';'",
+            "unexpected trailing character ']'",
+            "unexpected end of input",
+        ];
+
+        let result: Vec<String> = vec!["[1,2", "[1;2]", "[1,2]]", ""]
+            .into_iter()
+            .map(|input| input.parse::<SnailfishNumber>().unwrap_err().message)
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_display() {
+        let input = EXAMPLES[0].get_lines();
+
+        let result: Vec<String> = input.iter().map(|s| parse(s).to_string()).collect();
+
+        assert_eq!(result, input);
+    }
+
+    #[test]
+    fn test_explode() {
+        let examples = vec![
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ];
+
+        for (input, expected) in examples {
+            let mut number = parse(input);
+
+            assert!(number.explode());
+            assert_eq!(number.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        let mut number = parse("[[[[0,7],4],[15,[0,13]]],[1,1]]");
+
+        assert!(number.split());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+
+        assert!(number.split());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+
+        assert!(!number.split());
+    }
+
+    #[test]
+    fn test_reduce_step_by_step() {
+        let mut number = parse("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+
+        assert!(number.explode());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]");
+
+        assert!(number.explode());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[15,[0,13]]],[1,1]]");
+
+        assert!(!number.explode());
+        assert!(number.split());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+
+        assert!(!number.explode());
+        assert!(number.split());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+
+        assert!(number.explode());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+        assert!(!number.explode());
+        assert!(!number.split());
+    }
+
+    #[test]
+    fn test_add() {
+        let expected = parse("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+        let result = parse("[[[[4,3],4],4],[7,[[8,4],9]]]") + parse("[1,1]");
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_magnitude() {
+        let expected = vec![29, 129, 1384, 445, 791, 1137, 3488];
+
+        let result: Vec<u32> = vec![
+            "[9,1]",
+            "[[9,1],[1,9]]",
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            "[[[[1,1],[2,2]],[3,3]],[4,4]]",
+            "[[[[3,0],[5,3]],[4,4]],[5,5]]",
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]",
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+        ]
+        .into_iter()
+        .map(|s| parse(s).get_magnitude())
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_sum() {
+        let input: Vec<SnailfishNumber> = EXAMPLES[0].input.lines().map(parse).collect();
+
+        let expected = parse("[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]");
+
+        let result = get_sum(&input).unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(result.get_magnitude(), 4140);
+    }
+
+    #[test]
+    fn test_get_largest_magnitude_of_any_two() {
+        let input: Vec<SnailfishNumber> = EXAMPLES[0].input.lines().map(parse).collect();
+
+        let expected = Some(3993);
+
+        let result = get_largest_magnitude_of_any_two(&input);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::util::file_reader::get_indexed_sections;
+use crate::util::location::Location;
+use crate::util::parse_error::{parse_lines, ParseError};
+use crate::util::point_3d::{Point3d, NUMBER_OF_ORIENTATIONS};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const MINIMUM_OVERLAPPING_BEACONS: usize = 12;
+const SCANNER_HEADER_FORMAT: &str = "--- scanner N ---";
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Scanner {
+    beacons: Vec<Point3d<i32>>,
+}
+
+impl Scanner {
+    pub fn new(beacons: Vec<Point3d<i32>>) -> Scanner {
+        Scanner { beacons }
+    }
+
+    fn get_rotated_beacons(&self, orientation: usize) -> Vec<Point3d<i32>> {
+        self.beacons
+            .iter()
+            .map(|beacon| beacon.rotate(orientation))
+            .collect()
+    }
+}
+
+impl TryFrom<&[String]> for Scanner {
+    type Error = ParseError;
+
+    fn try_from(input: &[String]) -> Result<Scanner, ParseError> {
+        let (header, beacon_lines) = input
+            .split_first()
+            .ok_or_else(|| ParseError::expected("", SCANNER_HEADER_FORMAT))?;
+
+        if !header.starts_with("--- scanner ") {
+            return Err(ParseError::expected(header, SCANNER_HEADER_FORMAT).at_line(1));
+        }
+
+        let beacons = parse_lines(beacon_lines).map_err(|error| error.offset_lines(1))?;
+
+        Ok(Scanner::new(beacons))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BeaconMap {
+    beacons: HashSet<Point3d<i32>>,
+    scanner_positions: Vec<Point3d<i32>>,
+}
+
+impl BeaconMap {
+    pub fn new(scanners: &[Scanner]) -> Option<BeaconMap> {
+        let mut aligned_beacons: Vec<Option<Vec<Point3d<i32>>>> = vec![None; scanners.len()];
+        let mut scanner_positions: Vec<Option<Point3d<i32>>> = vec![None; scanners.len()];
+
+        let first_scanner = scanners.first()?;
+
+        aligned_beacons[0] = Some(first_scanner.beacons.clone());
+        scanner_positions[0] = Some(Point3d::new(0, 0, 0));
+
+        let mut reference_indices = VecDeque::from([0]);
+
+        while let Some(reference_index) = reference_indices.pop_front() {
+            let reference_beacons = aligned_beacons[reference_index].clone().unwrap();
+
+            for (index, scanner) in scanners.iter().enumerate() {
+                if scanner_positions[index].is_some() {
+                    continue;
+                }
+
+                if let Some((position, beacons)) = Self::align(&reference_beacons, scanner) {
+                    aligned_beacons[index] = Some(beacons);
+                    scanner_positions[index] = Some(position);
+
+                    reference_indices.push_back(index);
+                }
+            }
+        }
+
+        let scanner_positions = scanner_positions.into_iter().collect::<Option<Vec<_>>>()?;
+
+        let beacons = aligned_beacons.into_iter().flatten().flatten().collect();
+
+        Some(BeaconMap {
+            beacons,
+            scanner_positions,
+        })
+    }
+
+    pub fn get_beacons(&self) -> &HashSet<Point3d<i32>> {
+        &self.beacons
+    }
+
+    pub fn get_scanner_positions(&self) -> &[Point3d<i32>] {
+        &self.scanner_positions
+    }
+
+    fn align(
+        reference_beacons: &[Point3d<i32>],
+        scanner: &Scanner,
+    ) -> Option<(Point3d<i32>, Vec<Point3d<i32>>)> {
+        for orientation in 0..NUMBER_OF_ORIENTATIONS {
+            let rotated_beacons = scanner.get_rotated_beacons(orientation);
+
+            let mut offset_counts: HashMap<Point3d<i32>, usize> = HashMap::new();
+
+            for reference_beacon in reference_beacons {
+                for rotated_beacon in rotated_beacons.iter() {
+                    let offset = *reference_beacon - *rotated_beacon;
+
+                    let count = offset_counts.entry(offset).or_default();
+
+                    *count += 1;
+
+                    if *count < MINIMUM_OVERLAPPING_BEACONS {
+                        continue;
+                    }
+
+                    let aligned_beacons = rotated_beacons
+                        .iter()
+                        .map(|&beacon| beacon + offset)
+                        .collect();
+
+                    return Some((offset, aligned_beacons));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+pub fn get_largest_manhattan_distance(points: &[Point3d<i32>]) -> Option<i32> {
+    let mut result = None;
+
+    for first in points.iter() {
+        for second in points.iter() {
+            let distance = first.manhattan_distance_to(second);
+
+            result = result.max(Some(distance));
+        }
+    }
+
+    result
+}
+
+pub fn get_scanners(input: &[String]) -> Result<Vec<Scanner>, ParseError> {
+    get_indexed_sections(input)
+        .into_iter()
+        .map(|(line_index, section)| {
+            Scanner::try_from(section).map_err(|error| error.offset_lines(line_index))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_19::EXAMPLES;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    const TEST_DATA: [&str; 9] = [
+        "--- scanner 0 ---",
+        "404,-588,-901",
+        "528,-643,409",
+        "-838,591,734",
+        "",
+        "--- scanner 1 ---",
+        "686,422,578",
+        "605,423,415",
+        "515,917,-361",
+    ];
+
+    const TEST_SCANNER_POSITIONS: [(i32, i32, i32); 5] = [
+        (0, 0, 0),
+        (68, -1246, -43),
+        (1105, -1205, 1229),
+        (-92, -2380, -20),
+        (-20, -1133, 1061),
+    ];
+
+    fn get_test_scanners() -> Vec<Scanner> {
+        get_scanners(&EXAMPLES[0].get_lines()).unwrap()
+    }
+
+    #[test]
+    fn test_get_scanners() {
+        let input = str_slice_to_string_vector(&TEST_DATA);
+
+        let expected = vec![
+            Scanner::new(vec![
+                Point3d::new(404, -588, -901),
+                Point3d::new(528, -643, 409),
+                Point3d::new(-838, 591, 734),
+            ]),
+            Scanner::new(vec![
+                Point3d::new(686, 422, 578),
+                Point3d::new(605, 423, 415),
+                Point3d::new(515, 917, -361),
+            ]),
+        ];
+
+        let result = get_scanners(&input);
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_get_scanners_error() {
+        let expected = vec!["4: expected '--- scanner N ---'", "5: expected 'x,y,z'"];
+
+        let result: Vec<String> = [
+            ["--- scanner 0 ---", "1,2,3", "", "scanner 1", "4,5,6"],
+            ["--- scanner 0 ---", "1,2,3", "", "--- scanner 1 ---", "4,5"],
+        ]
+        .iter()
+        .map(|lines| {
+            get_scanners(&str_slice_to_string_vector(lines))
+                .unwrap_err()
+                .to_string()
+        })
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_beacon_map_get_scanner_positions() {
+        let scanners = get_test_scanners();
+
+        let expected: Vec<Point3d<i32>> = TEST_SCANNER_POSITIONS
+            .iter()
+            .map(|&(x, y, z)| Point3d::new(x, y, z))
+            .collect();
+
+        let beacon_map = BeaconMap::new(&scanners).unwrap();
+
+        let result = beacon_map.get_scanner_positions();
+
+        assert_eq!(result, expected.as_slice());
+    }
+
+    #[test]
+    fn test_beacon_map_get_beacons() {
+        let scanners = get_test_scanners();
+
+        let expected_count = 79;
+        let expected_beacons = [
+            Point3d::new(-892, 524, 684),
+            Point3d::new(-618, -824, -621),
+            Point3d::new(459, -707, 401),
+            Point3d::new(1889, -1729, 1762),
+        ];
+
+        let beacon_map = BeaconMap::new(&scanners).unwrap();
+
+        let result = beacon_map.get_beacons();
+
+        assert_eq!(result.len(), expected_count);
+        assert!(expected_beacons
+            .iter()
+            .all(|beacon| result.contains(beacon)));
+    }
+
+    #[test]
+    fn test_get_largest_manhattan_distance() {
+        let scanners = get_test_scanners();
+
+        let beacon_map = BeaconMap::new(&scanners).unwrap();
+
+        let expected = Some(3621);
+
+        let result = get_largest_manhattan_distance(beacon_map.get_scanner_positions());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_beacon_map_new_not_enough_overlap() {
+        let mut scanners = get_test_scanners();
+
+        scanners[1].beacons.remove(0);
+
+        let result = BeaconMap::new(&scanners[..2]);
+
+        assert_eq!(result, None);
+    }
+}
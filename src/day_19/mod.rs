@@ -1,8 +1,43 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod beacon_scanner;
 
-pub fn run_day_19() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_19.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use beacon_scanner::{get_largest_manhattan_distance, get_scanners, BeaconMap, Scanner};
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../../test_inputs/day_19.txt"),
+    part_one: Some("79"),
+    part_two: Some("3621"),
+}];
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+
+    fn parse(&self, input: &[String]) -> Result<Vec<Scanner>, ParseError> {
+        get_scanners(input)
+    }
+
+    fn part_one(&self, scanners: &Vec<Scanner>) -> Result<Answer, String> {
+        let beacon_map = get_beacon_map(scanners)?;
+
+        Ok(beacon_map.get_beacons().len().into())
+    }
+
+    fn part_two(&self, scanners: &Vec<Scanner>) -> Result<Answer, String> {
+        let beacon_map = get_beacon_map(scanners)?;
+
+        get_largest_manhattan_distance(beacon_map.get_scanner_positions())
+            .map(Answer::from)
+            .ok_or_else(|| String::from("Day 19 file was empty!"))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+}
+
+fn get_beacon_map(scanners: &[Scanner]) -> Result<BeaconMap, String> {
+    BeaconMap::new(scanners).ok_or_else(|| String::from("Could not align all scanners!"))
 }
@@ -1,15 +1,44 @@
 mod pilot_computer;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+use crate::util::point_3d::Point3d;
+pub use pilot_computer::{get_final_location, get_relative_locations};
 
-pub fn run_day_2() {
-    let file_input = to_string_vector("inputs/day_2.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2",
+    part_one: Some("150"),
+    part_two: Some("900"),
+}];
 
-    let submarine_location = pilot_computer::get_final_location(&file_input);
+pub struct Day2;
 
-    let part_1_result = submarine_location.x.abs() * submarine_location.y.abs();
-    let part_2_result = submarine_location.x.abs() * submarine_location.z.abs();
+impl Solution for Day2 {
+    type Input = Vec<Point3d<i32>>;
 
-    println!("Day 2 Part 1: {}", part_1_result);
-    println!("Day 2 Part 2: {}", part_2_result);
+    fn parse(&self, input: &[String]) -> Result<Vec<Point3d<i32>>, ParseError> {
+        pilot_computer::get_relative_locations(input)
+    }
+
+    fn part_one(&self, relative_locations: &Vec<Point3d<i32>>) -> Result<Answer, String> {
+        let submarine_location = pilot_computer::get_final_location(relative_locations);
+
+        Ok((submarine_location.x.abs() * submarine_location.y.abs()).into())
+    }
+
+    fn part_two(&self, relative_locations: &Vec<Point3d<i32>>) -> Result<Answer, String> {
+        let submarine_location = pilot_computer::get_final_location(relative_locations);
+
+        Ok((submarine_location.x.abs() * submarine_location.z.abs()).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
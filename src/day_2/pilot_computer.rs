@@ -1,11 +1,17 @@
+use crate::util::parse_error::{parse_lines_with, parse_number, ParseError};
 use crate::util::point_3d::Point3d;
 
-pub fn get_final_location(input: &[String]) -> Point3d<i32> {
-    input
+const INSTRUCTION_FORMAT: &str = "forward|down|up distance";
+
+pub fn get_relative_locations(input: &[String]) -> Result<Vec<Point3d<i32>>, ParseError> {
+    parse_lines_with(input, convert_instruction_to_relative_location)
+}
+
+pub fn get_final_location(relative_locations: &[Point3d<i32>]) -> Point3d<i32> {
+    relative_locations
         .iter()
-        .map(|instruction| convert_instruction_to_relative_location(instruction))
         .fold(Point3d::new(0, 0, 0), |running_sum, relative_location| {
-            next_location(&running_sum, &relative_location)
+            next_location(&running_sum, relative_location)
         })
 }
 
@@ -17,44 +23,35 @@ fn next_location(current_location: &Point3d<i32>, location_change: &Point3d<i32>
     Point3d::new(x_position, aim, depth)
 }
 
-fn convert_instruction_to_relative_location(instruction: &str) -> Point3d<i32> {
+fn convert_instruction_to_relative_location(instruction: &str) -> Result<Point3d<i32>, ParseError> {
     let (direction, distance_str) = instruction
         .split_once(' ')
-        .expect(&format!("Cannot split instuction: {}", instruction));
+        .ok_or_else(|| ParseError::expected(instruction, INSTRUCTION_FORMAT))?;
 
-    let distance: i32 = distance_str
-        .parse()
-        .expect(&format!("Cannot parse distance: {}", distance_str));
+    let distance: i32 =
+        parse_number(distance_str).map_err(|error| error.at_column(direction.len() + 2))?;
 
     match direction {
-        "forward" => Point3d::new(distance, 0, 0),
-        "down" => Point3d::new(0, -distance, 0),
-        "up" => Point3d::new(0, distance, 0),
-        _ => panic!("Unexpected direction: {}", direction),
+        "forward" => Ok(Point3d::new(distance, 0, 0)),
+        "down" => Ok(Point3d::new(0, -distance, 0)),
+        "up" => Ok(Point3d::new(0, distance, 0)),
+        _ => Err(ParseError::expected(instruction, INSTRUCTION_FORMAT).at_column(1)),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_2::EXAMPLES;
     use crate::util::point_3d::Point3d;
 
-    const TEST_DATA: [&str; 6] = [
-        "forward 5",
-        "down 5",
-        "forward 8",
-        "up 3",
-        "down 8",
-        "forward 2",
-    ];
-
     #[test]
     fn test_get_final_location() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
         let expected = Point3d::new(15, -10, -60);
 
-        let result = get_final_location(&input);
+        let result = get_final_location(&get_relative_locations(&input).unwrap());
 
         assert_eq!(result, expected);
     }
@@ -77,12 +74,26 @@ mod tests {
         let expected_down = Point3d::new(0, -5, 0);
         let expected_up = Point3d::new(0, 3, 0);
 
-        let result_forward = convert_instruction_to_relative_location(TEST_DATA[0]);
-        let result_down = convert_instruction_to_relative_location(TEST_DATA[1]);
-        let result_up = convert_instruction_to_relative_location(TEST_DATA[3]);
+        let result_forward = convert_instruction_to_relative_location("forward 5");
+        let result_down = convert_instruction_to_relative_location("down 5");
+        let result_up = convert_instruction_to_relative_location("up 3");
+
+        assert_eq!(result_forward, Ok(expected_forward));
+        assert_eq!(result_down, Ok(expected_down));
+        assert_eq!(result_up, Ok(expected_up));
+    }
 
-        assert_eq!(result_forward, expected_forward);
-        assert_eq!(result_down, expected_down);
-        assert_eq!(result_up, expected_up);
+    #[test]
+    fn test_get_relative_locations_error() {
+        let input: Vec<String> = ["forward 5", "sideways 2"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let expected = "2:1: expected 'forward|down|up distance'";
+
+        let result = get_relative_locations(&input).unwrap_err().to_string();
+
+        assert_eq!(result, expected);
     }
 }
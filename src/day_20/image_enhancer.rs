@@ -0,0 +1,326 @@
+use crate::util::parse_error::ParseError;
+use crate::util::point_2d::Point2d;
+use std::collections::HashSet;
+use std::fmt;
+
+const ALGORITHM_SIZE: usize = 512;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImageEnhancer {
+    algorithm: Vec<bool>,
+    lit_pixels: HashSet<Point2d<i32>>,
+    min: Point2d<i32>,
+    max: Point2d<i32>,
+    background_is_lit: bool,
+}
+
+impl ImageEnhancer {
+    pub fn enhance(&mut self) {
+        let new_min = Point2d::new(self.min.x - 1, self.min.y - 1);
+        let new_max = Point2d::new(self.max.x + 1, self.max.y + 1);
+
+        let mut new_lit_pixels = HashSet::new();
+
+        for y in new_min.y..=new_max.y {
+            for x in new_min.x..=new_max.x {
+                let point = Point2d::new(x, y);
+
+                if self.algorithm[self.get_algorithm_index(&point)] {
+                    new_lit_pixels.insert(point);
+                }
+            }
+        }
+
+        let background_index = if self.background_is_lit {
+            ALGORITHM_SIZE - 1
+        } else {
+            0
+        };
+
+        self.background_is_lit = self.algorithm[background_index];
+        self.lit_pixels = new_lit_pixels;
+        self.min = new_min;
+        self.max = new_max;
+    }
+
+    pub fn enhance_n_times(&mut self, passes: usize) {
+        for _ in 0..passes {
+            self.enhance();
+        }
+    }
+
+    pub fn get_number_of_lit_pixels(&self) -> Option<usize> {
+        if self.background_is_lit {
+            None
+        } else {
+            Some(self.lit_pixels.len())
+        }
+    }
+
+    pub fn is_lit(&self, point: &Point2d<i32>) -> bool {
+        if self.is_in_bounds(point) {
+            self.lit_pixels.contains(point)
+        } else {
+            self.background_is_lit
+        }
+    }
+
+    fn is_in_bounds(&self, point: &Point2d<i32>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    fn get_algorithm_index(&self, center: &Point2d<i32>) -> usize {
+        let mut result = 0;
+
+        for y in (center.y - 1)..=(center.y + 1) {
+            for x in (center.x - 1)..=(center.x + 1) {
+                let bit = usize::from(self.is_lit(&Point2d::new(x, y)));
+
+                result = result * 2 + bit;
+            }
+        }
+
+        result
+    }
+
+    fn parse_row(row: &str) -> Result<Vec<bool>, ParseError> {
+        row.chars()
+            .enumerate()
+            .map(|(index, pixel)| match pixel {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(ParseError::new(
+                    row,
+                    &format!("expected '#' or '.', but got '{}'", pixel),
+                )
+                .at_column(index + 1)),
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<&[String]> for ImageEnhancer {
+    type Error = ParseError;
+
+    fn try_from(input: &[String]) -> Result<ImageEnhancer, ParseError> {
+        let (algorithm_line, image_lines) = input
+            .split_first()
+            .ok_or_else(|| ParseError::new("", "expected an image enhancement algorithm"))?;
+
+        let algorithm = Self::parse_row(algorithm_line).map_err(|error| error.at_line(1))?;
+
+        if algorithm.len() != ALGORITHM_SIZE {
+            return Err(ParseError::new(
+                algorithm_line,
+                &format!(
+                    "expected {} pixels in the algorithm, but got {}",
+                    ALGORITHM_SIZE,
+                    algorithm.len()
+                ),
+            )
+            .at_line(1));
+        }
+
+        let (separator, image_lines) = image_lines
+            .split_first()
+            .ok_or_else(|| ParseError::new("", "expected a blank line").at_line(2))?;
+
+        if !separator.is_empty() {
+            return Err(ParseError::new(
+                separator,
+                &format!("expected a blank line, but got '{}'", separator),
+            )
+            .at_line(2));
+        }
+
+        let width = image_lines
+            .first()
+            .map(|row| row.len())
+            .ok_or_else(|| ParseError::new("", "expected an image").at_line(3))?;
+        let height = image_lines.len();
+
+        let mut lit_pixels = HashSet::new();
+
+        for (y, row) in image_lines.iter().enumerate() {
+            let pixels = Self::parse_row(row).map_err(|error| error.at_line(y + 3))?;
+
+            if pixels.len() != width {
+                return Err(ParseError::new(
+                    row,
+                    &format!("expected {} pixels, but got {}", width, pixels.len()),
+                )
+                .at_line(y + 3));
+            }
+
+            for (x, is_lit) in pixels.into_iter().enumerate() {
+                if is_lit {
+                    lit_pixels.insert(Point2d::new(x as i32, y as i32));
+                }
+            }
+        }
+
+        Ok(ImageEnhancer {
+            algorithm,
+            lit_pixels,
+            min: Point2d::new(0, 0),
+            max: Point2d::new(width as i32 - 1, height as i32 - 1),
+            background_is_lit: false,
+        })
+    }
+}
+
+impl fmt::Display for ImageEnhancer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in self.min.y..=self.max.y {
+            let row: String = (self.min.x..=self.max.x)
+                .map(|x| {
+                    if self.is_lit(&Point2d::new(x, y)) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+
+            writeln!(f, "{}", row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_20::EXAMPLES;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    #[test]
+    fn test_try_from() {
+        let input = EXAMPLES[0].get_lines();
+
+        let enhancer = ImageEnhancer::try_from(input.as_slice()).unwrap();
+
+        let expected_algorithm_head = vec![false, false, true, false, true];
+        let expected_number_of_lit_pixels = Some(10);
+
+        assert_eq!(enhancer.algorithm[..5], expected_algorithm_head);
+        assert_eq!(
+            enhancer.get_number_of_lit_pixels(),
+            expected_number_of_lit_pixels
+        );
+    }
+
+    #[test]
+    fn test_try_from_error() {
+        let algorithm = EXAMPLES[0].input.lines().next().unwrap();
+
+        let expected = vec![
+            "5:4: expected '#' or '.', but got 'x'",
+            "2: expected a blank line, but got '#..#.'",
+            "5: expected 5 pixels, but got 0",
+            "4: expected 5 pixels, but got 4",
+            "3: expected an image",
+        ];
+
+        let result: Vec<String> = [
+            vec![algorithm, "", "#..#.", "#....", "##.x#"],
+            vec![algorithm, "#..#.", "#....", "##..#"],
+            vec![algorithm, "", "#..#.", "#....", "", "##..#"],
+            vec![algorithm, "", "#..#.", "#...", "##..#"],
+            vec![algorithm, ""],
+        ]
+        .iter()
+        .map(|input| {
+            let input = str_slice_to_string_vector(input);
+
+            ImageEnhancer::try_from(input.as_slice())
+                .unwrap_err()
+                .to_string()
+        })
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_display() {
+        let input = EXAMPLES[0].get_lines();
+
+        let enhancer = ImageEnhancer::try_from(input.as_slice()).unwrap();
+
+        let expected = "#..#.\n#....\n##..#\n..#..\n..###\n";
+
+        let result = enhancer.to_string();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_enhance_display() {
+        let input = EXAMPLES[0].get_lines();
+
+        let mut enhancer = ImageEnhancer::try_from(input.as_slice()).unwrap();
+
+        enhancer.enhance();
+
+        let expected = vec![
+            ".##.##.", "#..#.#.", "##.#..#", "####..#", ".#..##.", "..##..#", "...#.#.",
+        ];
+
+        let result = enhancer.to_string();
+
+        assert_eq!(result.lines().collect::<Vec<&str>>(), expected);
+    }
+
+    #[test]
+    fn test_enhance_n_times_two() {
+        let input = EXAMPLES[0].get_lines();
+
+        let mut enhancer = ImageEnhancer::try_from(input.as_slice()).unwrap();
+
+        enhancer.enhance_n_times(2);
+
+        let expected = Some(35);
+
+        let result = enhancer.get_number_of_lit_pixels();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_enhance_n_times_fifty() {
+        let input = EXAMPLES[0].get_lines();
+
+        let mut enhancer = ImageEnhancer::try_from(input.as_slice()).unwrap();
+
+        enhancer.enhance_n_times(50);
+
+        let expected = Some(3351);
+
+        let result = enhancer.get_number_of_lit_pixels();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_enhance_flipping_background() {
+        let algorithm: String = (0..ALGORITHM_SIZE)
+            .map(|index| if index == 0 { '#' } else { '.' })
+            .collect();
+
+        let input = vec![algorithm, String::new(), String::from("...")];
+
+        let mut enhancer = ImageEnhancer::try_from(input.as_slice()).unwrap();
+
+        enhancer.enhance();
+
+        assert_eq!(enhancer.get_number_of_lit_pixels(), None);
+        assert!(enhancer.is_lit(&Point2d::new(100, -100)));
+
+        enhancer.enhance();
+
+        assert_eq!(enhancer.get_number_of_lit_pixels(), Some(0));
+        assert!(!enhancer.is_lit(&Point2d::new(100, -100)));
+    }
+}
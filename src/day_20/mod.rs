@@ -1,8 +1,59 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod image_enhancer;
 
-pub fn run_day_20() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_20.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use image_enhancer::ImageEnhancer;
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...\
+####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#\
+..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#......\
+.#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.##\
+#.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..\
+####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###",
+    part_one: Some("35"),
+    part_two: Some("3351"),
+}];
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = ImageEnhancer;
+
+    fn parse(&self, input: &[String]) -> Result<ImageEnhancer, ParseError> {
+        ImageEnhancer::try_from(input)
+    }
+
+    fn part_one(&self, enhancer: &ImageEnhancer) -> Result<Answer, String> {
+        get_number_of_lit_pixels_after(enhancer, 2)
+    }
+
+    fn part_two(&self, enhancer: &ImageEnhancer) -> Result<Answer, String> {
+        get_number_of_lit_pixels_after(enhancer, 50)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+}
+
+fn get_number_of_lit_pixels_after(
+    enhancer: &ImageEnhancer,
+    number_of_enhancements: usize,
+) -> Result<Answer, String> {
+    let mut enhancer = enhancer.clone();
+
+    enhancer.enhance_n_times(number_of_enhancements);
+
+    enhancer
+        .get_number_of_lit_pixels()
+        .map(Answer::from)
+        .ok_or_else(|| String::from("Infinitely many pixels are lit!"))
 }
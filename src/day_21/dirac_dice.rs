@@ -0,0 +1,415 @@
+use crate::util::parse_error::{parse_lines_with, parse_number, ParseError};
+use std::collections::HashMap;
+
+const STARTING_POSITION_FORMAT: &str = "Player N starting position: P";
+
+pub const PRACTICE_GAME_RULES: GameRules = GameRules {
+    board_size: 10,
+    winning_score: 1000,
+    rolls_per_turn: 3,
+};
+
+pub const DIRAC_GAME_RULES: GameRules = GameRules {
+    board_size: 10,
+    winning_score: 21,
+    rolls_per_turn: 3,
+};
+
+pub trait Die {
+    fn roll(&mut self) -> u32;
+
+    fn get_number_of_rolls(&self) -> usize;
+}
+
+pub trait SplittingDie {
+    fn get_roll_outcomes(&self) -> Vec<(u32, u64)>;
+
+    fn get_turn_outcomes(&self, rolls_per_turn: usize) -> Vec<(u32, u64)> {
+        let roll_outcomes = self.get_roll_outcomes();
+
+        let mut sums_to_universes: HashMap<u32, u64> = HashMap::from([(0, 1)]);
+
+        for _ in 0..rolls_per_turn {
+            let mut temp_map = HashMap::new();
+
+            for (sum, universes) in sums_to_universes {
+                for &(value, value_universes) in roll_outcomes.iter() {
+                    *temp_map.entry(sum + value).or_default() += universes * value_universes;
+                }
+            }
+
+            sums_to_universes = temp_map;
+        }
+
+        let mut result: Vec<(u32, u64)> = sums_to_universes.into_iter().collect();
+
+        result.sort_unstable();
+
+        result
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DeterministicDie {
+    sides: u32,
+    next_value: u32,
+    number_of_rolls: usize,
+}
+
+impl DeterministicDie {
+    pub fn new(sides: u32) -> Result<DeterministicDie, String> {
+        if sides == 0 {
+            return Err(String::from("Die must have at least 1 side!"));
+        }
+
+        Ok(DeterministicDie {
+            sides,
+            next_value: 1,
+            number_of_rolls: 0,
+        })
+    }
+}
+
+impl Die for DeterministicDie {
+    fn roll(&mut self) -> u32 {
+        let result = self.next_value;
+
+        self.next_value = self.next_value % self.sides + 1;
+        self.number_of_rolls += 1;
+
+        result
+    }
+
+    fn get_number_of_rolls(&self) -> usize {
+        self.number_of_rolls
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct QuantumDie {
+    sides: u32,
+}
+
+impl QuantumDie {
+    pub fn new(sides: u32) -> QuantumDie {
+        QuantumDie { sides }
+    }
+}
+
+impl SplittingDie for QuantumDie {
+    fn get_roll_outcomes(&self) -> Vec<(u32, u64)> {
+        (1..=self.sides).map(|value| (value, 1)).collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct GameRules {
+    pub board_size: u32,
+    pub winning_score: u32,
+    pub rolls_per_turn: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct GameOutcome {
+    pub scores: [u32; 2],
+    pub number_of_rolls: usize,
+}
+
+impl GameOutcome {
+    pub fn get_losing_score(&self) -> u32 {
+        self.scores[0].min(self.scores[1])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+struct QuantumState {
+    current_position: u32,
+    current_score: u32,
+    other_position: u32,
+    other_score: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DiracDiceGame {
+    rules: GameRules,
+    starting_positions: [u32; 2],
+}
+
+impl DiracDiceGame {
+    pub fn new(rules: GameRules, starting_positions: [u32; 2]) -> Result<DiracDiceGame, String> {
+        if rules.board_size == 0 {
+            return Err(String::from("Board size must be at least 1!"));
+        }
+
+        if let Some(position) = starting_positions
+            .iter()
+            .find(|&&position| !(1..=rules.board_size).contains(&position))
+        {
+            return Err(format!(
+                "Starting position {} is not between 1 and {}!",
+                position, rules.board_size
+            ));
+        }
+
+        Ok(DiracDiceGame {
+            rules,
+            starting_positions,
+        })
+    }
+
+    pub fn play<D: Die>(&self, die: &mut D) -> GameOutcome {
+        let mut positions = self.starting_positions;
+        let mut scores = [0, 0];
+
+        let mut player = 0;
+
+        while scores.iter().all(|&score| score < self.rules.winning_score) {
+            let moves: u32 = (0..self.rules.rolls_per_turn).map(|_| die.roll()).sum();
+
+            positions[player] = self.get_next_position(positions[player], moves);
+            scores[player] += positions[player];
+
+            player = 1 - player;
+        }
+
+        GameOutcome {
+            scores,
+            number_of_rolls: die.get_number_of_rolls(),
+        }
+    }
+
+    pub fn count_quantum_wins<D: SplittingDie>(&self, die: &D) -> [u64; 2] {
+        let turn_outcomes = die.get_turn_outcomes(self.rules.rolls_per_turn);
+
+        let starting_state = QuantumState {
+            current_position: self.starting_positions[0],
+            current_score: 0,
+            other_position: self.starting_positions[1],
+            other_score: 0,
+        };
+
+        let mut states_to_wins = HashMap::new();
+
+        self.count_quantum_wins_from(starting_state, &turn_outcomes, &mut states_to_wins)
+    }
+
+    fn count_quantum_wins_from(
+        &self,
+        state: QuantumState,
+        turn_outcomes: &[(u32, u64)],
+        states_to_wins: &mut HashMap<QuantumState, [u64; 2]>,
+    ) -> [u64; 2] {
+        if let Some(&result) = states_to_wins.get(&state) {
+            return result;
+        }
+
+        let mut result = [0, 0];
+
+        for &(moves, universes) in turn_outcomes {
+            let position = self.get_next_position(state.current_position, moves);
+            let score = state.current_score + position;
+
+            if self.rules.winning_score <= score {
+                result[0] += universes;
+
+                continue;
+            }
+
+            let next_state = QuantumState {
+                current_position: state.other_position,
+                current_score: state.other_score,
+                other_position: position,
+                other_score: score,
+            };
+
+            let [other_wins, current_wins] =
+                self.count_quantum_wins_from(next_state, turn_outcomes, states_to_wins);
+
+            result[0] += current_wins * universes;
+            result[1] += other_wins * universes;
+        }
+
+        states_to_wins.insert(state, result);
+
+        result
+    }
+
+    fn get_next_position(&self, position: u32, moves: u32) -> u32 {
+        (position - 1 + moves) % self.rules.board_size + 1
+    }
+}
+
+pub fn get_starting_positions(input: &[String]) -> Result<[u32; 2], ParseError> {
+    let positions = parse_lines_with(input, |line| {
+        line.split_once(": ")
+            .ok_or_else(|| ParseError::expected(line, STARTING_POSITION_FORMAT))
+            .and_then(|(_, position_str)| parse_number(position_str))
+    })?;
+
+    match positions.as_slice() {
+        [first, second] => Ok([*first, *second]),
+        _ => Err(ParseError::new(
+            "",
+            &format!("expected 2 starting positions, but got {}", positions.len()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_21::EXAMPLES;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    #[test]
+    fn test_get_starting_positions() {
+        let input = EXAMPLES[0].get_lines();
+
+        let expected = [4, 8];
+
+        let result = get_starting_positions(&input);
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_get_starting_positions_error() {
+        let expected = vec![
+            "2: expected 'Player N starting position: P'",
+            "expected 2 starting positions, but got 1",
+            "1: expected a number, but got '-1'",
+        ];
+
+        let result: Vec<String> = [
+            vec![
+                "Player 1 starting position: 4",
+                "Player 2 starting position 8",
+            ],
+            vec!["Player 1 starting position: 4"],
+            vec![
+                "Player 1 starting position: -1",
+                "Player 2 starting position: 8",
+            ],
+        ]
+        .iter()
+        .map(|lines| {
+            get_starting_positions(&str_slice_to_string_vector(lines))
+                .unwrap_err()
+                .to_string()
+        })
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_deterministic_die_roll() {
+        let mut die = DeterministicDie::new(3).unwrap();
+
+        let expected = vec![1, 2, 3, 1, 2];
+
+        let result: Vec<u32> = (0..5).map(|_| die.roll()).collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(die.get_number_of_rolls(), 5);
+    }
+
+    #[test]
+    fn test_deterministic_die_new_error() {
+        let result = DeterministicDie::new(0);
+
+        assert_eq!(result, Err(String::from("Die must have at least 1 side!")));
+    }
+
+    #[test]
+    fn test_quantum_die_get_turn_outcomes() {
+        let die = QuantumDie::new(3);
+
+        let expected = vec![(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+        let result = die.get_turn_outcomes(3);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_splitting_die_get_turn_outcomes() {
+        struct CoinDie;
+
+        impl SplittingDie for CoinDie {
+            fn get_roll_outcomes(&self) -> Vec<(u32, u64)> {
+                vec![(1, 1), (2, 2)]
+            }
+        }
+
+        let expected = vec![(2, 1), (3, 4), (4, 4)];
+
+        let result = CoinDie.get_turn_outcomes(2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_new_error() {
+        let rules = GameRules {
+            board_size: 0,
+            ..DIRAC_GAME_RULES
+        };
+
+        assert!(DiracDiceGame::new(rules, [1, 1]).is_err());
+        assert!(DiracDiceGame::new(DIRAC_GAME_RULES, [0, 8]).is_err());
+        assert!(DiracDiceGame::new(DIRAC_GAME_RULES, [4, 11]).is_err());
+    }
+
+    #[test]
+    fn test_play() {
+        let game = DiracDiceGame::new(PRACTICE_GAME_RULES, [4, 8]).unwrap();
+
+        let mut die = DeterministicDie::new(100).unwrap();
+
+        let expected = GameOutcome {
+            scores: [1000, 745],
+            number_of_rolls: 993,
+        };
+
+        let result = game.play(&mut die);
+
+        assert_eq!(result, expected);
+        assert_eq!(result.get_losing_score(), 745);
+    }
+
+    #[test]
+    fn test_play_custom_rules() {
+        let rules = GameRules {
+            board_size: 5,
+            winning_score: 10,
+            rolls_per_turn: 1,
+        };
+
+        let game = DiracDiceGame::new(rules, [1, 1]).unwrap();
+
+        let mut die = DeterministicDie::new(6).unwrap();
+
+        let expected = GameOutcome {
+            scores: [12, 5],
+            number_of_rolls: 5,
+        };
+
+        let result = game.play(&mut die);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_count_quantum_wins() {
+        let game = DiracDiceGame::new(DIRAC_GAME_RULES, [4, 8]).unwrap();
+
+        let die = QuantumDie::new(3);
+
+        let expected = [444_356_092_776_315, 341_960_390_180_808];
+
+        let result = game.count_quantum_wins(&die);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -1,8 +1,47 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod dirac_dice;
 
-pub fn run_day_21() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_21.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use dirac_dice::{
+    get_starting_positions, DeterministicDie, Die, DiracDiceGame, GameOutcome, GameRules,
+    QuantumDie, SplittingDie, DIRAC_GAME_RULES, PRACTICE_GAME_RULES,
+};
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+Player 1 starting position: 4
+Player 2 starting position: 8",
+    part_one: Some("739785"),
+    part_two: Some("444356092776315"),
+}];
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = [u32; 2];
+
+    fn parse(&self, input: &[String]) -> Result<[u32; 2], ParseError> {
+        get_starting_positions(input)
+    }
+
+    fn part_one(&self, starting_positions: &[u32; 2]) -> Result<Answer, String> {
+        let practice_game = DiracDiceGame::new(PRACTICE_GAME_RULES, *starting_positions)?;
+        let practice_outcome = practice_game.play(&mut DeterministicDie::new(100)?);
+
+        Ok(
+            (practice_outcome.get_losing_score() as usize * practice_outcome.number_of_rolls)
+                .into(),
+        )
+    }
+
+    fn part_two(&self, starting_positions: &[u32; 2]) -> Result<Answer, String> {
+        let dirac_game = DiracDiceGame::new(DIRAC_GAME_RULES, *starting_positions)?;
+        let dirac_wins = dirac_game.count_quantum_wins(&QuantumDie::new(3));
+
+        Ok(dirac_wins[0].max(dirac_wins[1]).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
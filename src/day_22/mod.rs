@@ -1,8 +1,49 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod reactor;
 
-pub fn run_day_22() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_22.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use reactor::{get_reboot_steps, Cuboid, Reactor, RebootStep, INITIALIZATION_REGION};
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10",
+    part_one: Some("39"),
+    part_two: Some("39"),
+}];
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<RebootStep>;
+
+    fn parse(&self, input: &[String]) -> Result<Vec<RebootStep>, ParseError> {
+        get_reboot_steps(input)
+    }
+
+    fn part_one(&self, reboot_steps: &Vec<RebootStep>) -> Result<Answer, String> {
+        Ok(get_rebooted_reactor(reboot_steps)
+            .get_number_of_lit_cubes_within(&INITIALIZATION_REGION)
+            .into())
+    }
+
+    fn part_two(&self, reboot_steps: &Vec<RebootStep>) -> Result<Answer, String> {
+        Ok(get_rebooted_reactor(reboot_steps)
+            .get_number_of_lit_cubes()
+            .into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+}
+
+fn get_rebooted_reactor(reboot_steps: &[RebootStep]) -> Reactor {
+    let mut reactor = Reactor::new();
+
+    reactor.apply_steps(reboot_steps);
+
+    reactor
 }
@@ -0,0 +1,354 @@
+use crate::util::parse_error::{parse_lines, parse_number, ParseError};
+use crate::util::point_3d::Point3d;
+use std::str::FromStr;
+
+const REBOOT_STEP_FORMAT: &str = "on|off x=min..max,y=min..max,z=min..max";
+
+pub const INITIALIZATION_REGION: Cuboid = Cuboid {
+    min: Point3d {
+        x: -50,
+        y: -50,
+        z: -50,
+    },
+    max: Point3d {
+        x: 50,
+        y: 50,
+        z: 50,
+    },
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Cuboid {
+    min: Point3d<i64>,
+    max: Point3d<i64>,
+}
+
+impl Cuboid {
+    fn new(min: Point3d<i64>, max: Point3d<i64>) -> Cuboid {
+        Cuboid { min, max }
+    }
+
+    pub fn get_volume(&self) -> i64 {
+        let size = self.max - self.min;
+
+        (size.x + 1) * (size.y + 1) * (size.z + 1)
+    }
+
+    pub fn get_intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = Point3d::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Point3d::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+
+        if (max.x < min.x) || (max.y < min.y) || (max.z < min.z) {
+            None
+        } else {
+            Some(Cuboid::new(min, max))
+        }
+    }
+
+    pub fn get_difference(&self, other: &Cuboid) -> Vec<Cuboid> {
+        let intersection = match self.get_intersection(other) {
+            Some(intersection) => intersection,
+            None => return vec![*self],
+        };
+
+        let mut result = Vec::new();
+        let mut remaining = *self;
+
+        if remaining.min.x < intersection.min.x {
+            let mut slab = remaining;
+            slab.max.x = intersection.min.x - 1;
+            remaining.min.x = intersection.min.x;
+            result.push(slab);
+        }
+
+        if intersection.max.x < remaining.max.x {
+            let mut slab = remaining;
+            slab.min.x = intersection.max.x + 1;
+            remaining.max.x = intersection.max.x;
+            result.push(slab);
+        }
+
+        if remaining.min.y < intersection.min.y {
+            let mut slab = remaining;
+            slab.max.y = intersection.min.y - 1;
+            remaining.min.y = intersection.min.y;
+            result.push(slab);
+        }
+
+        if intersection.max.y < remaining.max.y {
+            let mut slab = remaining;
+            slab.min.y = intersection.max.y + 1;
+            remaining.max.y = intersection.max.y;
+            result.push(slab);
+        }
+
+        if remaining.min.z < intersection.min.z {
+            let mut slab = remaining;
+            slab.max.z = intersection.min.z - 1;
+            result.push(slab);
+        }
+
+        if intersection.max.z < remaining.max.z {
+            let mut slab = remaining;
+            slab.min.z = intersection.max.z + 1;
+            result.push(slab);
+        }
+
+        result
+    }
+
+    fn parse_range(range: &str, axis: &str) -> Result<(i64, i64), ParseError> {
+        let (min_str, max_str) = range
+            .strip_prefix(axis)
+            .and_then(|range| range.split_once(".."))
+            .ok_or_else(|| ParseError::expected(range, REBOOT_STEP_FORMAT))?;
+
+        let (min, max) = (parse_number(min_str)?, parse_number(max_str)?);
+
+        if max < min {
+            return Err(ParseError::new(
+                range,
+                &format!("expected min <= max, but got {}..{}", min, max),
+            ));
+        }
+
+        Ok((min, max))
+    }
+}
+
+impl FromStr for Cuboid {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Cuboid, ParseError> {
+        let ranges: Vec<&str> = input.split(',').collect();
+
+        match ranges.as_slice() {
+            [x_range, y_range, z_range] => {
+                let (min_x, max_x) = Self::parse_range(x_range, "x=")?;
+                let (min_y, max_y) = Self::parse_range(y_range, "y=")?;
+                let (min_z, max_z) = Self::parse_range(z_range, "z=")?;
+
+                Ok(Cuboid::new(
+                    Point3d::new(min_x, min_y, min_z),
+                    Point3d::new(max_x, max_y, max_z),
+                ))
+            }
+            _ => Err(ParseError::expected(input, REBOOT_STEP_FORMAT)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RebootStep {
+    pub turn_on: bool,
+    pub cuboid: Cuboid,
+}
+
+impl FromStr for RebootStep {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<RebootStep, ParseError> {
+        let (turn_on, cuboid_str) = match input.split_once(' ') {
+            Some(("on", cuboid_str)) => (true, cuboid_str),
+            Some(("off", cuboid_str)) => (false, cuboid_str),
+            _ => return Err(ParseError::expected(input, REBOOT_STEP_FORMAT)),
+        };
+
+        Ok(RebootStep {
+            turn_on,
+            cuboid: cuboid_str.parse()?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Reactor {
+    lit_cuboids: Vec<Cuboid>,
+}
+
+impl Reactor {
+    pub fn new() -> Reactor {
+        Reactor {
+            lit_cuboids: Vec::new(),
+        }
+    }
+
+    pub fn apply_step(&mut self, step: &RebootStep) {
+        self.lit_cuboids = self
+            .lit_cuboids
+            .iter()
+            .flat_map(|lit_cuboid| lit_cuboid.get_difference(&step.cuboid))
+            .collect();
+
+        if step.turn_on {
+            self.lit_cuboids.push(step.cuboid);
+        }
+    }
+
+    pub fn apply_steps(&mut self, steps: &[RebootStep]) {
+        steps.iter().for_each(|step| self.apply_step(step));
+    }
+
+    pub fn get_number_of_lit_cubes(&self) -> i64 {
+        self.lit_cuboids.iter().map(Cuboid::get_volume).sum()
+    }
+
+    pub fn get_number_of_lit_cubes_within(&self, region: &Cuboid) -> i64 {
+        self.lit_cuboids
+            .iter()
+            .filter_map(|lit_cuboid| lit_cuboid.get_intersection(region))
+            .map(|intersection| intersection.get_volume())
+            .sum()
+    }
+}
+
+pub fn get_reboot_steps(input: &[String]) -> Result<Vec<RebootStep>, ParseError> {
+    parse_lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_22::EXAMPLES;
+
+    fn cuboid(min: (i64, i64, i64), max: (i64, i64, i64)) -> Cuboid {
+        Cuboid::new(
+            Point3d::new(min.0, min.1, min.2),
+            Point3d::new(max.0, max.1, max.2),
+        )
+    }
+
+    #[test]
+    fn test_reboot_step_from_str() {
+        let expected = RebootStep {
+            turn_on: false,
+            cuboid: cuboid((-54112, -85059, -27449), (-39298, -49293, 7877)),
+        };
+
+        let result = "off x=-54112..-39298,y=-85059..-49293,z=-27449..7877".parse();
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_reboot_step_from_str_error() {
+        let format_error = "expected 'on|off x=min..max,y=min..max,z=min..max'";
+
+        let expected = vec![
+            format_error,
+            format_error,
+            format_error,
+            "expected a number, but got '1O'",
+            "expected min <= max, but got 13..11",
+        ];
+
+        let result: Vec<String> = [
+            "toggle x=9..11,y=9..11,z=9..11",
+            "on x=9..11,y=9..11",
+            "on x=9..11,y=9..11,w=9..11",
+            "on x=9..11,y=9..1O,z=9..11",
+            "on x=11..13,y=13..11,z=11..13",
+        ]
+        .iter()
+        .map(|line| line.parse::<RebootStep>().unwrap_err().message)
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_cuboid_get_volume() {
+        let expected = 27;
+
+        let result = cuboid((10, 10, 10), (12, 12, 12)).get_volume();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_cuboid_get_intersection() {
+        let first = cuboid((10, 10, 10), (12, 12, 12));
+        let second = cuboid((11, 9, 12), (13, 11, 20));
+        let third = cuboid((13, 10, 10), (14, 12, 12));
+
+        assert_eq!(
+            first.get_intersection(&second),
+            Some(cuboid((11, 10, 12), (12, 11, 12)))
+        );
+        assert_eq!(first.get_intersection(&third), None);
+    }
+
+    #[test]
+    fn test_cuboid_get_difference() {
+        let first = cuboid((0, 0, 0), (9, 9, 9));
+        let second = cuboid((3, 3, 3), (5, 5, 5));
+
+        let result = first.get_difference(&second);
+
+        let result_volume: i64 = result.iter().map(Cuboid::get_volume).sum();
+
+        assert_eq!(result.len(), 6);
+        assert_eq!(result_volume, 1000 - 27);
+
+        for (index, piece) in result.iter().enumerate() {
+            assert_eq!(piece.get_intersection(&second), None);
+
+            for other_piece in result.iter().skip(index + 1) {
+                assert_eq!(piece.get_intersection(other_piece), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cuboid_get_difference_disjoint_and_covered() {
+        let first = cuboid((0, 0, 0), (1, 1, 1));
+        let second = cuboid((5, 5, 5), (6, 6, 6));
+        let covering = cuboid((-1, -1, -1), (2, 2, 2));
+
+        assert_eq!(first.get_difference(&second), vec![first]);
+        assert_eq!(first.get_difference(&covering), vec![]);
+    }
+
+    #[test]
+    fn test_reactor_get_number_of_lit_cubes() {
+        let input = EXAMPLES[0].get_lines();
+
+        let mut reactor = Reactor::new();
+
+        reactor.apply_steps(&get_reboot_steps(&input).unwrap());
+
+        let expected = 39;
+
+        let result = reactor.get_number_of_lit_cubes();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_reactor_get_number_of_lit_cubes_within() {
+        let mut input = EXAMPLES[0].get_lines();
+
+        input.push(String::from("on x=-100..100,y=0..0,z=0..0"));
+
+        let mut reactor = Reactor::new();
+
+        reactor.apply_steps(&get_reboot_steps(&input).unwrap());
+
+        let expected_unbounded = 39 + 201;
+        let expected_within = 39 + 101;
+
+        let result_unbounded = reactor.get_number_of_lit_cubes();
+        let result_within = reactor.get_number_of_lit_cubes_within(&INITIALIZATION_REGION);
+
+        assert_eq!(result_unbounded, expected_unbounded);
+        assert_eq!(result_within, expected_within);
+    }
+}
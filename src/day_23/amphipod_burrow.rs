@@ -0,0 +1,444 @@
+use crate::util::parse_error::ParseError;
+use crate::util::search::dijkstra;
+use std::fmt;
+
+const HALLWAY_LENGTH: usize = 11;
+const NUMBER_OF_ROOMS: usize = 4;
+const ROOM_ENTRANCES: [usize; NUMBER_OF_ROOMS] = [2, 4, 6, 8];
+
+const FOLDED_ROOM_DEPTH: usize = 2;
+const UNFOLDED_ROWS: [[Amphipod; NUMBER_OF_ROOMS]; 2] = [
+    [
+        Amphipod::Desert,
+        Amphipod::Copper,
+        Amphipod::Bronze,
+        Amphipod::Amber,
+    ],
+    [
+        Amphipod::Desert,
+        Amphipod::Bronze,
+        Amphipod::Amber,
+        Amphipod::Copper,
+    ],
+];
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    fn get_energy_per_step(&self) -> u32 {
+        match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        }
+    }
+
+    fn get_target_room(&self) -> usize {
+        match self {
+            Amphipod::Amber => 0,
+            Amphipod::Bronze => 1,
+            Amphipod::Copper => 2,
+            Amphipod::Desert => 3,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        }
+    }
+}
+
+impl TryFrom<char> for Amphipod {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Amphipod, ParseError> {
+        match input {
+            'A' => Ok(Amphipod::Amber),
+            'B' => Ok(Amphipod::Bronze),
+            'C' => Ok(Amphipod::Copper),
+            'D' => Ok(Amphipod::Desert),
+            _ => Err(ParseError::new(
+                &input.to_string(),
+                &format!("expected an amphipod, but got '{}'", input),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Burrow {
+    hallway: [Option<Amphipod>; HALLWAY_LENGTH],
+    rooms: [Vec<Option<Amphipod>>; NUMBER_OF_ROOMS],
+}
+
+impl Burrow {
+    pub fn get_room_depth(&self) -> usize {
+        self.rooms[0].len()
+    }
+
+    pub fn unfold(&self) -> Result<Burrow, String> {
+        if self.get_room_depth() != FOLDED_ROOM_DEPTH {
+            return Err(format!(
+                "Only burrows with rooms {} deep can be unfolded, but got {}",
+                FOLDED_ROOM_DEPTH,
+                self.get_room_depth()
+            ));
+        }
+
+        let mut result = self.clone();
+
+        for (row_index, row) in UNFOLDED_ROWS.iter().enumerate() {
+            for (room, &amphipod) in row.iter().enumerate() {
+                result.rooms[room].insert(row_index + 1, Some(amphipod));
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub fn is_organized(&self) -> bool {
+        self.rooms.iter().enumerate().all(|(room_index, room)| {
+            room.iter()
+                .all(|space| space.map(|amphipod| amphipod.get_target_room()) == Some(room_index))
+        })
+    }
+
+    pub fn get_least_energy_solution(&self) -> Option<(u32, Vec<Burrow>)> {
+        dijkstra(self.clone(), Burrow::get_next_burrows, Burrow::is_organized)
+    }
+
+    pub fn get_least_energy_solution_diagram(&self) -> Option<String> {
+        let (_, burrows) = self.get_least_energy_solution()?;
+
+        let mut result = format!("Step 0 (energy 0):\n{}\n", self);
+        let mut energy = 0;
+
+        for (step, window) in burrows.windows(2).enumerate() {
+            energy += window[0].get_energy_to(&window[1])?;
+
+            result.push_str(&format!(
+                "Step {} (energy {}):\n{}\n",
+                step + 1,
+                energy,
+                window[1]
+            ));
+        }
+
+        Some(result)
+    }
+
+    fn get_energy_to(&self, other: &Burrow) -> Option<u32> {
+        self.get_next_burrows()
+            .into_iter()
+            .find(|(burrow, _)| burrow == other)
+            .map(|(_, energy)| energy)
+    }
+
+    fn get_next_burrows(&self) -> Vec<(Burrow, u32)> {
+        let mut result = Vec::new();
+
+        for (hallway_index, space) in self.hallway.iter().enumerate() {
+            if let Some(amphipod) = space {
+                if let Some(next) = self.move_into_room(hallway_index, *amphipod) {
+                    result.push(next);
+                }
+            }
+        }
+
+        for room_index in 0..NUMBER_OF_ROOMS {
+            result.extend(self.move_out_of_room(room_index));
+        }
+
+        result
+    }
+
+    fn move_into_room(&self, hallway_index: usize, amphipod: Amphipod) -> Option<(Burrow, u32)> {
+        let room_index = amphipod.get_target_room();
+        let room = &self.rooms[room_index];
+
+        let room_has_strangers = room
+            .iter()
+            .flatten()
+            .any(|occupant| occupant.get_target_room() != room_index);
+
+        if room_has_strangers || !self.is_hallway_clear(hallway_index, ROOM_ENTRANCES[room_index]) {
+            return None;
+        }
+
+        let depth = room.iter().rposition(|space| space.is_none())?;
+
+        let steps = hallway_index.abs_diff(ROOM_ENTRANCES[room_index]) + depth + 1;
+
+        let mut next = self.clone();
+
+        next.hallway[hallway_index] = None;
+        next.rooms[room_index][depth] = Some(amphipod);
+
+        Some((next, steps as u32 * amphipod.get_energy_per_step()))
+    }
+
+    fn move_out_of_room(&self, room_index: usize) -> Vec<(Burrow, u32)> {
+        let room = &self.rooms[room_index];
+
+        let depth = match room.iter().position(|space| space.is_some()) {
+            Some(depth) => depth,
+            None => return Vec::new(),
+        };
+
+        let is_settled = room[depth..]
+            .iter()
+            .flatten()
+            .all(|occupant| occupant.get_target_room() == room_index);
+
+        if is_settled {
+            return Vec::new();
+        }
+
+        let amphipod = room[depth].unwrap();
+        let entrance = ROOM_ENTRANCES[room_index];
+
+        (0..HALLWAY_LENGTH)
+            .filter(|hallway_index| !ROOM_ENTRANCES.contains(hallway_index))
+            .filter(|&hallway_index| self.is_hallway_clear(entrance, hallway_index))
+            .map(|hallway_index| {
+                let steps = hallway_index.abs_diff(entrance) + depth + 1;
+
+                let mut next = self.clone();
+
+                next.rooms[room_index][depth] = None;
+                next.hallway[hallway_index] = Some(amphipod);
+
+                (next, steps as u32 * amphipod.get_energy_per_step())
+            })
+            .collect()
+    }
+
+    fn is_hallway_clear(&self, from: usize, to: usize) -> bool {
+        let range = if from < to {
+            (from + 1)..=to
+        } else {
+            to..=(from - 1)
+        };
+
+        self.hallway[range].iter().all(|space| space.is_none())
+    }
+
+    fn parse_room_row(row: &str) -> Result<Vec<Amphipod>, ParseError> {
+        row.chars()
+            .enumerate()
+            .filter(|(_, c)| c.is_ascii_uppercase())
+            .map(|(index, c)| Amphipod::try_from(c).map_err(|error| error.at_column(index + 1)))
+            .collect()
+    }
+}
+
+impl TryFrom<&[String]> for Burrow {
+    type Error = ParseError;
+
+    fn try_from(input: &[String]) -> Result<Burrow, ParseError> {
+        let mut rooms: [Vec<Option<Amphipod>>; NUMBER_OF_ROOMS] = Default::default();
+
+        for (line_index, row) in input.iter().enumerate().skip(2) {
+            let amphipods =
+                Self::parse_room_row(row).map_err(|error| error.at_line(line_index + 1))?;
+
+            if amphipods.is_empty() {
+                continue;
+            }
+
+            if amphipods.len() != NUMBER_OF_ROOMS {
+                return Err(ParseError::new(
+                    row,
+                    &format!(
+                        "expected {} amphipods, but got {}",
+                        NUMBER_OF_ROOMS,
+                        amphipods.len()
+                    ),
+                )
+                .at_line(line_index + 1));
+            }
+
+            for (room, amphipod) in amphipods.into_iter().enumerate() {
+                rooms[room].push(Some(amphipod));
+            }
+        }
+
+        if rooms[0].is_empty() {
+            return Err(ParseError::new(
+                "",
+                "expected rows of amphipods in the rooms",
+            ));
+        }
+
+        Ok(Burrow {
+            hallway: [None; HALLWAY_LENGTH],
+            rooms,
+        })
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let to_char = |space: &Option<Amphipod>| space.map_or('.', Amphipod::to_char);
+
+        let hallway: String = self.hallway.iter().map(to_char).collect();
+
+        writeln!(f, "#############")?;
+        writeln!(f, "#{}#", hallway)?;
+
+        for depth in 0..self.get_room_depth() {
+            let spaces: Vec<String> = self
+                .rooms
+                .iter()
+                .map(|room| to_char(&room[depth]).to_string())
+                .collect();
+
+            if depth == 0 {
+                writeln!(f, "###{}###", spaces.join("#"))?;
+            } else {
+                writeln!(f, "  #{}#", spaces.join("#"))?;
+            }
+        }
+
+        write!(f, "  #########")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_23::EXAMPLES;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    #[test]
+    fn test_try_from_error() {
+        let expected = vec![
+            "3:6: expected an amphipod, but got 'E'",
+            "3: expected 4 amphipods, but got 3",
+            "expected rows of amphipods in the rooms",
+        ];
+
+        let result: Vec<String> = ["###B#E#B#D###", "###B#C#.#D###", "###.#.#.#.###"]
+            .iter()
+            .map(|row| {
+                let input = str_slice_to_string_vector(&[
+                    "#############",
+                    "#...........#",
+                    row,
+                    "  #########",
+                ]);
+
+                Burrow::try_from(input.as_slice()).unwrap_err().to_string()
+            })
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_from_display() {
+        let input = EXAMPLES[0].get_lines();
+
+        let expected = EXAMPLES[0].input;
+
+        let result = Burrow::try_from(input.as_slice()).unwrap().to_string();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unfold() {
+        let input = EXAMPLES[0].get_lines();
+
+        let expected = [
+            "#############",
+            "#...........#",
+            "###B#C#B#D###",
+            "  #D#C#B#A#",
+            "  #D#B#A#C#",
+            "  #A#D#C#A#",
+            "  #########",
+        ]
+        .join("\n");
+
+        let burrow = Burrow::try_from(input.as_slice())
+            .unwrap()
+            .unfold()
+            .unwrap();
+
+        assert_eq!(burrow.get_room_depth(), 4);
+        assert_eq!(burrow.to_string(), expected);
+    }
+
+    #[test]
+    fn test_unfold_error() {
+        let input = str_slice_to_string_vector(&[
+            "#############",
+            "#...........#",
+            "###B#C#B#D###",
+            "  #D#C#B#A#",
+            "  #D#B#A#C#",
+            "  #A#D#C#A#",
+            "  #########",
+        ]);
+
+        let result = Burrow::try_from(input.as_slice()).unwrap().unfold();
+
+        assert_eq!(
+            result,
+            Err(String::from(
+                "Only burrows with rooms 2 deep can be unfolded, but got 4"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_get_least_energy_solution() {
+        let input = EXAMPLES[0].get_lines();
+
+        let burrow = Burrow::try_from(input.as_slice()).unwrap();
+
+        let (energy, burrows) = burrow.get_least_energy_solution().unwrap();
+
+        assert_eq!(energy, 12521);
+        assert_eq!(burrows.first(), Some(&burrow));
+        assert!(burrows.last().unwrap().is_organized());
+    }
+
+    #[test]
+    fn test_get_least_energy_solution_unfolded() {
+        let input = EXAMPLES[0].get_lines();
+
+        let burrow = Burrow::try_from(input.as_slice())
+            .unwrap()
+            .unfold()
+            .unwrap();
+
+        let result = burrow.get_least_energy_solution().map(|(energy, _)| energy);
+
+        assert_eq!(result, Some(44169));
+    }
+
+    #[test]
+    fn test_get_least_energy_solution_diagram() {
+        let input = EXAMPLES[0].get_lines();
+
+        let burrow = Burrow::try_from(input.as_slice()).unwrap();
+
+        let result = burrow.get_least_energy_solution_diagram().unwrap();
+
+        assert!(result.starts_with(&format!("Step 0 (energy 0):\n{}\n", burrow)));
+        assert!(result.contains("(energy 12521):\n#############\n#...........#\n###A#B#C#D###"));
+    }
+}
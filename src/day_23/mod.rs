@@ -1,8 +1,65 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod amphipod_burrow;
 
-pub fn run_day_23() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_23.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Part, Solution};
+use crate::util::parse_error::ParseError;
+pub use amphipod_burrow::Burrow;
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########",
+    part_one: Some("12521"),
+    part_two: Some("44169"),
+}];
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+
+    fn parse(&self, input: &[String]) -> Result<Burrow, ParseError> {
+        Burrow::try_from(input)
+    }
+
+    fn part_one(&self, burrow: &Burrow) -> Result<Answer, String> {
+        get_least_energy(burrow)
+    }
+
+    fn part_two(&self, burrow: &Burrow) -> Result<Answer, String> {
+        get_least_energy(&burrow.unfold()?)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+
+    fn diagram(&self, burrow: &Burrow, part: Part) -> Option<Result<String, String>> {
+        let burrow = match part {
+            Part::One => burrow.clone(),
+            Part::Two => match burrow.unfold() {
+                Ok(burrow) => burrow,
+                Err(error) => return Some(Err(error)),
+            },
+        };
+
+        Some(
+            burrow
+                .get_least_energy_solution_diagram()
+                .ok_or_else(get_no_solution_error),
+        )
+    }
+}
+
+fn get_least_energy(burrow: &Burrow) -> Result<Answer, String> {
+    burrow
+        .get_least_energy_solution()
+        .map(|(energy, _)| energy.into())
+        .ok_or_else(get_no_solution_error)
+}
+
+fn get_no_solution_error() -> String {
+    String::from("Burrow has no solution!")
 }
@@ -0,0 +1,332 @@
+use crate::util::parse_error::{parse_lines, ParseError};
+use std::str::FromStr;
+
+const INSTRUCTION_FORMAT: &str = "inp a|add|mul|div|mod|eql a b";
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    fn get_index(&self) -> usize {
+        match self {
+            Register::W => 0,
+            Register::X => 1,
+            Register::Y => 2,
+            Register::Z => 3,
+        }
+    }
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Register, ParseError> {
+        match input {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(ParseError::new(
+                input,
+                &format!("expected a register, but got '{}'", input),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+impl FromStr for Operand {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Operand, ParseError> {
+        match input.parse::<i64>() {
+            Ok(value) => Ok(Operand::Value(value)),
+            Err(_) => input.parse().map(Operand::Register),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Instruction {
+    Input(Register),
+    Add(Register, Operand),
+    Multiply(Register, Operand),
+    Divide(Register, Operand),
+    Modulo(Register, Operand),
+    Equal(Register, Operand),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Instruction, ParseError> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+
+        match parts.as_slice() {
+            ["inp", register] => Ok(Instruction::Input(register.parse()?)),
+            [operation, register, operand] => {
+                let register = register.parse()?;
+                let operand = operand.parse()?;
+
+                match *operation {
+                    "add" => Ok(Instruction::Add(register, operand)),
+                    "mul" => Ok(Instruction::Multiply(register, operand)),
+                    "div" => Ok(Instruction::Divide(register, operand)),
+                    "mod" => Ok(Instruction::Modulo(register, operand)),
+                    "eql" => Ok(Instruction::Equal(register, operand)),
+                    _ => Err(ParseError::expected(input, INSTRUCTION_FORMAT)),
+                }
+            }
+            _ => Err(ParseError::expected(input, INSTRUCTION_FORMAT)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn new() -> Alu {
+        Alu { registers: [0; 4] }
+    }
+
+    pub fn get_register(&self, register: Register) -> i64 {
+        self.registers[register.get_index()]
+    }
+
+    pub fn execute<I>(&mut self, program: &[Instruction], inputs: &mut I) -> Result<(), String>
+    where
+        I: Iterator<Item = i64>,
+    {
+        program
+            .iter()
+            .try_for_each(|instruction| self.execute_instruction(instruction, inputs))
+    }
+
+    fn execute_instruction<I>(
+        &mut self,
+        instruction: &Instruction,
+        inputs: &mut I,
+    ) -> Result<(), String>
+    where
+        I: Iterator<Item = i64>,
+    {
+        let (register, value) = match *instruction {
+            Instruction::Input(register) => {
+                let value = inputs
+                    .next()
+                    .ok_or_else(|| String::from("Ran out of input values!"))?;
+
+                (register, value)
+            }
+            Instruction::Add(register, operand) => {
+                let sum = self
+                    .get_register(register)
+                    .checked_add(self.get_value(operand))
+                    .ok_or_else(|| format!("Overflow: {:?}", instruction))?;
+
+                (register, sum)
+            }
+            Instruction::Multiply(register, operand) => {
+                let product = self
+                    .get_register(register)
+                    .checked_mul(self.get_value(operand))
+                    .ok_or_else(|| format!("Overflow: {:?}", instruction))?;
+
+                (register, product)
+            }
+            Instruction::Divide(register, operand) => {
+                let divisor = self.get_value(operand);
+
+                if divisor == 0 {
+                    return Err(format!("Division by zero: {:?}", instruction));
+                }
+
+                let quotient = self
+                    .get_register(register)
+                    .checked_div(divisor)
+                    .ok_or_else(|| format!("Overflow: {:?}", instruction))?;
+
+                (register, quotient)
+            }
+            Instruction::Modulo(register, operand) => {
+                let dividend = self.get_register(register);
+                let divisor = self.get_value(operand);
+
+                if (dividend < 0) || (divisor <= 0) {
+                    return Err(format!(
+                        "Invalid modulo {} % {}: {:?}",
+                        dividend, divisor, instruction
+                    ));
+                }
+
+                let remainder = dividend
+                    .checked_rem(divisor)
+                    .ok_or_else(|| format!("Overflow: {:?}", instruction))?;
+
+                (register, remainder)
+            }
+            Instruction::Equal(register, operand) => {
+                let is_equal = self.get_register(register) == self.get_value(operand);
+
+                (register, i64::from(is_equal))
+            }
+        };
+
+        self.registers[register.get_index()] = value;
+
+        Ok(())
+    }
+
+    fn get_value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get_register(register),
+            Operand::Value(value) => value,
+        }
+    }
+}
+
+pub fn parse_program(input: &[String]) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    const TEST_THREE_TIMES_PROGRAM: [&str; 4] = ["inp z", "inp x", "mul z 3", "eql z x"];
+    const TEST_BINARY_PROGRAM: [&str; 11] = [
+        "inp w", "add z w", "mod z 2", "div w 2", "add y w", "mod y 2", "div w 2", "add x w",
+        "mod x 2", "div w 2", "mod w 2",
+    ];
+
+    #[test]
+    fn test_instruction_from_str() {
+        let expected = vec![
+            Instruction::Input(Register::W),
+            Instruction::Multiply(Register::X, Operand::Value(-1)),
+            Instruction::Equal(Register::Z, Operand::Register(Register::W)),
+        ];
+
+        let result: Vec<Instruction> = vec!["inp w", "mul x -1", "eql z w"]
+            .into_iter()
+            .map(|line| line.parse().unwrap())
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_instruction_from_str_error() {
+        let result: Vec<bool> = vec!["inp", "inp q", "sub x 1", "add x", "add x y z"]
+            .into_iter()
+            .map(|line| line.parse::<Instruction>().is_err())
+            .collect();
+
+        assert_eq!(result, vec![true; 5]);
+    }
+
+    #[test]
+    fn test_parse_program_error() {
+        let input = str_slice_to_string_vector(&["inp w", "add q 1"]);
+
+        let result = parse_program(&input).map_err(|error| error.to_string());
+
+        assert_eq!(
+            result,
+            Err(String::from("2: expected a register, but got 'q'"))
+        );
+    }
+
+    #[test]
+    fn test_parse_program_blank_line() {
+        let input = str_slice_to_string_vector(&["inp w", "", "add x 1"]);
+
+        let result = parse_program(&input).map_err(|error| error.line);
+
+        assert_eq!(result, Err(Some(2)));
+    }
+
+    #[test]
+    fn test_execute_negate() {
+        let program = parse_program(&str_slice_to_string_vector(&["inp x", "mul x -1"])).unwrap();
+
+        let mut alu = Alu::new();
+
+        alu.execute(&program, &mut vec![7].into_iter()).unwrap();
+
+        assert_eq!(alu.get_register(Register::X), -7);
+    }
+
+    #[test]
+    fn test_execute_three_times() {
+        let program =
+            parse_program(&str_slice_to_string_vector(&TEST_THREE_TIMES_PROGRAM)).unwrap();
+
+        let expected = vec![1, 0];
+
+        let result: Vec<i64> = vec![vec![4, 12], vec![4, 13]]
+            .into_iter()
+            .map(|inputs| {
+                let mut alu = Alu::new();
+
+                alu.execute(&program, &mut inputs.into_iter()).unwrap();
+
+                alu.get_register(Register::Z)
+            })
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_execute_binary() {
+        let program = parse_program(&str_slice_to_string_vector(&TEST_BINARY_PROGRAM)).unwrap();
+
+        let mut alu = Alu::new();
+
+        alu.execute(&program, &mut vec![10].into_iter()).unwrap();
+
+        let expected = vec![1, 0, 1, 0];
+
+        let result: Vec<i64> = vec![Register::W, Register::X, Register::Y, Register::Z]
+            .into_iter()
+            .map(|register| alu.get_register(register))
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_execute_errors() {
+        let programs: Vec<Vec<&str>> = vec![
+            vec!["inp w"],
+            vec!["div w 0"],
+            vec!["mod w -2"],
+            vec!["add w 9223372036854775807", "add w 1"],
+            vec!["add w 4611686018427387904", "mul w 2"],
+            vec!["add w -9223372036854775808", "div w -1"],
+        ];
+
+        for program in programs {
+            let program = parse_program(&str_slice_to_string_vector(&program)).unwrap();
+
+            assert!(Alu::new()
+                .execute(&program, &mut vec![].into_iter())
+                .is_err());
+        }
+    }
+}
@@ -1,8 +1,31 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod alu;
+mod monad_analyzer;
 
-pub fn run_day_24() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_24.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Solution};
+use crate::util::parse_error::ParseError;
+pub use alu::{parse_program, Alu, Instruction, Operand, Register};
+pub use monad_analyzer::MonadAnalyzer;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = MonadAnalyzer;
+
+    fn parse(&self, input: &[String]) -> Result<MonadAnalyzer, ParseError> {
+        MonadAnalyzer::new(&parse_program(input)?)
+    }
+
+    fn part_one(&self, analyzer: &MonadAnalyzer) -> Result<Answer, String> {
+        analyzer
+            .get_largest_model_number()
+            .map(Answer::from)
+            .ok_or_else(|| String::from("No valid model number!"))
+    }
+
+    fn part_two(&self, analyzer: &MonadAnalyzer) -> Result<Answer, String> {
+        analyzer
+            .get_smallest_model_number()
+            .map(Answer::from)
+            .ok_or_else(|| String::from("No valid model number!"))
+    }
 }
@@ -0,0 +1,265 @@
+use super::alu::{Alu, Instruction, Operand, Register};
+use crate::util::parse_error::ParseError;
+
+const NUMBER_OF_DIGITS: usize = 14;
+const BLOCK_LENGTH: usize = 18;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+struct BlockParameters {
+    z_divisor: i64,
+    x_increase: i64,
+    y_increase: i64,
+}
+
+impl BlockParameters {
+    fn is_push(&self) -> bool {
+        self.z_divisor == 1
+    }
+}
+
+impl TryFrom<&[Instruction]> for BlockParameters {
+    type Error = ParseError;
+
+    fn try_from(block: &[Instruction]) -> Result<BlockParameters, ParseError> {
+        if block.len() != BLOCK_LENGTH {
+            return Err(ParseError::new(
+                "",
+                &format!(
+                    "expected a block of {} instructions, but got {}",
+                    BLOCK_LENGTH,
+                    block.len()
+                ),
+            ));
+        }
+
+        match (block[0], block[4], block[5], block[15]) {
+            (
+                Instruction::Input(Register::W),
+                Instruction::Divide(Register::Z, Operand::Value(z_divisor)),
+                Instruction::Add(Register::X, Operand::Value(x_increase)),
+                Instruction::Add(Register::Y, Operand::Value(y_increase)),
+            ) if (z_divisor == 1) || (z_divisor == 26) => Ok(BlockParameters {
+                z_divisor,
+                x_increase,
+                y_increase,
+            }),
+            _ => Err(ParseError::new(
+                "",
+                "block does not match the MONAD pattern",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MonadAnalyzer {
+    program: Vec<Instruction>,
+    blocks: Vec<BlockParameters>,
+}
+
+impl MonadAnalyzer {
+    pub fn new(program: &[Instruction]) -> Result<MonadAnalyzer, ParseError> {
+        let blocks = program
+            .chunks(BLOCK_LENGTH)
+            .enumerate()
+            .map(|(block_index, block)| {
+                BlockParameters::try_from(block)
+                    .map_err(|error| error.at_line(block_index * BLOCK_LENGTH + 1))
+            })
+            .collect::<Result<Vec<BlockParameters>, ParseError>>()?;
+
+        if blocks.len() != NUMBER_OF_DIGITS {
+            return Err(ParseError::new(
+                "",
+                &format!(
+                    "expected {} blocks, but got {}",
+                    NUMBER_OF_DIGITS,
+                    blocks.len()
+                ),
+            ));
+        }
+
+        Ok(MonadAnalyzer {
+            program: program.to_vec(),
+            blocks,
+        })
+    }
+
+    pub fn get_largest_model_number(&self) -> Option<u64> {
+        self.get_model_number(|difference| (9 - difference.max(0), 9 + difference.min(0)))
+    }
+
+    pub fn get_smallest_model_number(&self) -> Option<u64> {
+        self.get_model_number(|difference| (1 - difference.min(0), 1 + difference.max(0)))
+    }
+
+    pub fn is_valid_model_number(&self, model_number: u64) -> Result<bool, String> {
+        let digits = Self::get_digits(model_number);
+
+        if (digits.len() != NUMBER_OF_DIGITS) || digits.contains(&0) {
+            return Ok(false);
+        }
+
+        let mut alu = Alu::new();
+
+        alu.execute(&self.program, &mut digits.into_iter())?;
+
+        Ok(alu.get_register(Register::Z) == 0)
+    }
+
+    fn get_model_number<F>(&self, choose_digits: F) -> Option<u64>
+    where
+        F: Fn(i64) -> (i64, i64),
+    {
+        let mut digits = [0; NUMBER_OF_DIGITS];
+        let mut pushed_blocks: Vec<(usize, i64)> = Vec::new();
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            if block.is_push() {
+                pushed_blocks.push((index, block.y_increase));
+
+                continue;
+            }
+
+            let (pushed_index, y_increase) = pushed_blocks.pop()?;
+
+            let difference = y_increase + block.x_increase;
+
+            let (pushed_digit, digit) = choose_digits(difference);
+
+            if !(1..=9).contains(&pushed_digit) || !(1..=9).contains(&digit) {
+                return None;
+            }
+
+            digits[pushed_index] = pushed_digit;
+            digits[index] = digit;
+        }
+
+        if !pushed_blocks.is_empty() {
+            return None;
+        }
+
+        let result = digits.iter().fold(0, |acc, &digit| acc * 10 + digit as u64);
+
+        match self.is_valid_model_number(result) {
+            Ok(true) => Some(result),
+            _ => None,
+        }
+    }
+
+    fn get_digits(model_number: u64) -> Vec<i64> {
+        model_number
+            .to_string()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as i64)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_BLOCK_PARAMETERS: [(i64, i64, i64); NUMBER_OF_DIGITS] = [
+        (1, 11, 6),
+        (1, 13, 14),
+        (1, 15, 14),
+        (26, -8, 10),
+        (1, 13, 9),
+        (1, 15, 12),
+        (26, -11, 8),
+        (26, -4, 13),
+        (26, -15, 12),
+        (1, 14, 6),
+        (1, 14, 9),
+        (26, -1, 15),
+        (26, -8, 4),
+        (26, -14, 10),
+    ];
+
+    fn get_test_program() -> Vec<Instruction> {
+        TEST_BLOCK_PARAMETERS
+            .iter()
+            .flat_map(|(z_divisor, x_increase, y_increase)| {
+                vec![
+                    "inp w".to_string(),
+                    "mul x 0".to_string(),
+                    "add x z".to_string(),
+                    "mod x 26".to_string(),
+                    format!("div z {}", z_divisor),
+                    format!("add x {}", x_increase),
+                    "eql x w".to_string(),
+                    "eql x 0".to_string(),
+                    "mul y 0".to_string(),
+                    "add y 25".to_string(),
+                    "mul y x".to_string(),
+                    "add y 1".to_string(),
+                    "mul z y".to_string(),
+                    "mul y 0".to_string(),
+                    "add y w".to_string(),
+                    format!("add y {}", y_increase),
+                    "mul y x".to_string(),
+                    "add z y".to_string(),
+                ]
+            })
+            .map(|line| line.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_new_error() {
+        let mut program = get_test_program();
+
+        program.truncate(BLOCK_LENGTH * 2);
+
+        assert!(MonadAnalyzer::new(&program).is_err());
+
+        program[BLOCK_LENGTH + 4] = "div z 3".parse().unwrap();
+
+        assert_eq!(
+            MonadAnalyzer::new(&program).map_err(|error| error.to_string()),
+            Err(String::from("19: block does not match the MONAD pattern"))
+        );
+    }
+
+    #[test]
+    fn test_get_largest_model_number() {
+        let analyzer = MonadAnalyzer::new(&get_test_program()).unwrap();
+
+        let expected = Some(99_394_899_891_971);
+
+        let result = analyzer.get_largest_model_number();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_smallest_model_number() {
+        let analyzer = MonadAnalyzer::new(&get_test_program()).unwrap();
+
+        let expected = Some(92_171_126_131_911);
+
+        let result = analyzer.get_smallest_model_number();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_is_valid_model_number() {
+        let analyzer = MonadAnalyzer::new(&get_test_program()).unwrap();
+
+        let expected = vec![Ok(true), Ok(false), Ok(false), Ok(false)];
+
+        let result: Vec<Result<bool, String>> = vec![
+            99_394_899_891_971,
+            99_394_899_891_972,
+            99_394_899_891_970,
+            9_939_489_989_197,
+        ]
+        .into_iter()
+        .map(|model_number| analyzer.is_valid_model_number(model_number))
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+}
@@ -1,8 +1,49 @@
-#![allow(unused_imports)]
-use crate::util::file_reader::to_string_vector;
+mod sea_cucumber_simulator;
 
-pub fn run_day_25() {
-    // Uncomment
-    // let file_input = to_string_vector("inputs/day_25.txt").unwrap();
-    todo!()
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use sea_cucumber_simulator::SeaCucumberSimulator;
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>",
+    part_one: Some("58"),
+    part_two: Some("Merry Christmas!"),
+}];
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = SeaCucumberSimulator;
+
+    fn parse(&self, input: &[String]) -> Result<SeaCucumberSimulator, ParseError> {
+        SeaCucumberSimulator::try_from(input)
+    }
+
+    fn part_one(&self, simulator: &SeaCucumberSimulator) -> Result<Answer, String> {
+        let first_step_without_movement = simulator
+            .clone()
+            .enumerate()
+            .find(|(_, number_moved)| *number_moved == 0)
+            .map(|(step, _)| step + 1)
+            .ok_or_else(|| String::from("Sea cucumbers never stop moving!"))?;
+
+        Ok(first_step_without_movement.into())
+    }
+
+    fn part_two(&self, _simulator: &SeaCucumberSimulator) -> Result<Answer, String> {
+        Ok("Merry Christmas!".into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -0,0 +1,216 @@
+use crate::util::parse_error::ParseError;
+use crate::util::point_2d::Point2d;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Herd {
+    East,
+    South,
+}
+
+impl Herd {
+    fn new_from(herd_char: char) -> Result<Option<Herd>, ParseError> {
+        match herd_char {
+            '>' => Ok(Some(Herd::East)),
+            'v' => Ok(Some(Herd::South)),
+            '.' => Ok(None),
+            _ => Err(ParseError::new(
+                &herd_char.to_string(),
+                &format!("expected '>', 'v' or '.', but got '{}'", herd_char),
+            )),
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Herd::East => '>',
+            Herd::South => 'v',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SeaCucumberSimulator {
+    sea_cucumbers: HashMap<Point2d<i32>, Herd>,
+    width: i32,
+    height: i32,
+    number_moved_so_far: usize,
+}
+
+impl TryFrom<&[String]> for SeaCucumberSimulator {
+    type Error = ParseError;
+
+    fn try_from(sea_cucumber_locations: &[String]) -> Result<SeaCucumberSimulator, ParseError> {
+        let mut map = HashMap::new();
+
+        for (row_index, row) in sea_cucumber_locations.iter().enumerate() {
+            for (column_index, column) in row.chars().enumerate() {
+                let herd = Herd::new_from(column)
+                    .map_err(|error| error.at_line(row_index + 1).at_column(column_index + 1))?;
+
+                if let Some(herd) = herd {
+                    map.insert(Point2d::new(column_index as i32, row_index as i32), herd);
+                }
+            }
+        }
+
+        let width = sea_cucumber_locations
+            .iter()
+            .map(|row| row.len())
+            .max()
+            .unwrap_or(0);
+
+        Ok(SeaCucumberSimulator {
+            sea_cucumbers: map,
+            width: width as i32,
+            height: sea_cucumber_locations.len() as i32,
+            number_moved_so_far: 0,
+        })
+    }
+}
+
+impl SeaCucumberSimulator {
+    fn execute_one_step(&mut self) -> usize {
+        self.move_herd(Herd::East) + self.move_herd(Herd::South)
+    }
+
+    fn move_herd(&mut self, herd: Herd) -> usize {
+        let moves: Vec<(Point2d<i32>, Point2d<i32>)> = self
+            .sea_cucumbers
+            .iter()
+            .filter(|(_, sea_cucumber)| **sea_cucumber == herd)
+            .map(|(location, _)| (*location, self.get_next_location(location, herd)))
+            .filter(|(_, next_location)| !self.sea_cucumbers.contains_key(next_location))
+            .collect();
+
+        for (location, next_location) in moves.iter() {
+            self.sea_cucumbers.remove(location);
+            self.sea_cucumbers.insert(*next_location, herd);
+        }
+
+        moves.len()
+    }
+
+    fn get_next_location(&self, location: &Point2d<i32>, herd: Herd) -> Point2d<i32> {
+        match herd {
+            Herd::East => Point2d::new((location.x + 1) % self.width, location.y),
+            Herd::South => Point2d::new(location.x, (location.y + 1) % self.height),
+        }
+    }
+}
+
+impl Iterator for SeaCucumberSimulator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.number_moved_so_far = self.execute_one_step();
+
+        Some(self.number_moved_so_far)
+    }
+}
+
+impl fmt::Display for SeaCucumberSimulator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            let row: String = (0..self.width)
+                .map(|x| {
+                    self.sea_cucumbers
+                        .get(&Point2d::new(x, y))
+                        .map_or('.', |herd| herd.to_char())
+                })
+                .collect();
+
+            writeln!(f, "{}", row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_25::EXAMPLES;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    const TEST_SMALL_DATA: [&str; 7] = [
+        "...>...", ".......", "......>", "v.....>", "......>", ".......", "..vvv..",
+    ];
+
+    #[test]
+    fn test_sea_cucumber_simulator_try_from_error() {
+        let input = str_slice_to_string_vector(&["..>", ".v.", "..<"]);
+
+        let result =
+            SeaCucumberSimulator::try_from(input.as_slice()).map_err(|error| error.to_string());
+
+        assert_eq!(
+            result,
+            Err(String::from("3:3: expected '>', 'v' or '.', but got '<'"))
+        );
+    }
+
+    #[test]
+    fn test_sea_cucumber_simulator_display() {
+        let input = EXAMPLES[0].get_lines();
+
+        let simulator = SeaCucumberSimulator::try_from(input.as_slice()).unwrap();
+
+        let expected = format!("{}\n", EXAMPLES[0].input);
+
+        let result = simulator.to_string();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sea_cucumber_simulator_execute_one_step_east_only() {
+        let input = str_slice_to_string_vector(&["...>>>>>..."]);
+
+        let mut simulator = SeaCucumberSimulator::try_from(input.as_slice()).unwrap();
+
+        assert_eq!(simulator.execute_one_step(), 1);
+        assert_eq!(simulator.to_string(), "...>>>>.>..\n");
+
+        assert_eq!(simulator.execute_one_step(), 2);
+        assert_eq!(simulator.to_string(), "...>>>.>.>.\n");
+    }
+
+    #[test]
+    fn test_sea_cucumber_simulator_execute_one_step_wraparound() {
+        let input = str_slice_to_string_vector(&TEST_SMALL_DATA);
+
+        let mut simulator = SeaCucumberSimulator::try_from(input.as_slice()).unwrap();
+
+        let expected = [
+            "..vv>..", ".......", ">......", "v.....>", ">......", ".......", "....v..",
+        ]
+        .join("\n")
+            + "\n";
+
+        simulator.execute_one_step();
+
+        let result = simulator.to_string();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sea_cucumber_simulator_iter_when_none_move() {
+        let input = EXAMPLES[0].get_lines();
+
+        let simulator = SeaCucumberSimulator::try_from(input.as_slice()).unwrap();
+
+        let expected = 58;
+
+        let result = simulator
+            .enumerate()
+            .find(|(_, number_moved)| *number_moved == 0)
+            .unwrap()
+            .0
+            + 1;
+
+        assert_eq!(result, expected);
+    }
+}
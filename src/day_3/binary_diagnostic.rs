@@ -1,20 +1,36 @@
-use std::u32;
+use crate::util::parse_error::{parse_lines_with, ParseError};
 
-pub fn get_power_consumption(input: &[String]) -> u32 {
-    let length_of_binary_string = input[0].len();
+pub fn get_diagnostic_report(input: &[String]) -> Result<Vec<String>, ParseError> {
+    let length_of_binary_string = input.first().map_or(0, |bit_string| bit_string.len());
+
+    parse_lines_with(input, |bit_string| {
+        if let Some(column_index) = bit_string.find(|bit_char| bit_char != '0' && bit_char != '1') {
+            return Err(
+                ParseError::new(bit_string, "expected a binary number").at_column(column_index + 1)
+            );
+        }
+
+        if bit_string.is_empty() || bit_string.len() != length_of_binary_string {
+            return Err(ParseError::new(
+                bit_string,
+                &format!("expected {} bits", length_of_binary_string.max(1)),
+            ));
+        }
+
+        Ok(bit_string.to_string())
+    })
+}
+
+pub fn get_power_consumption(input: &[String]) -> Result<u32, String> {
+    let length_of_binary_string = get_length_of_binary_strings(input)?;
 
     let mut counter = vec![0; length_of_binary_string];
 
     for bit_string in input {
-        for (index, bit_char) in bit_string.chars().enumerate() {
-            let current_column_count = counter
-                .get_mut(index)
-                .expect(&format!("Can't get count at index: {}", index));
-
+        for (current_column_count, bit_char) in counter.iter_mut().zip(bit_string.chars()) {
             match bit_char {
                 '0' => *current_column_count -= 1,
-                '1' => *current_column_count += 1,
-                _ => panic!("Unknown bit {} in bit_string {}", bit_char, bit_string),
+                _ => *current_column_count += 1,
             };
         }
     }
@@ -22,11 +38,11 @@ pub fn get_power_consumption(input: &[String]) -> u32 {
     let gamma_rate = array_of_bits_to_decimal(&get_most_signifcant_bits(&counter));
     let epsilon_rate = array_of_bits_to_decimal(&get_least_signifcant_bits(&counter));
 
-    gamma_rate * epsilon_rate
+    Ok(gamma_rate * epsilon_rate)
 }
 
-pub fn get_life_support_rating(input: &[String]) -> u32 {
-    let length_of_binary_string = input[0].len();
+pub fn get_life_support_rating(input: &[String]) -> Result<u32, String> {
+    let length_of_binary_string = get_length_of_binary_strings(input)?;
 
     let mut oxygen_generator_values = input.to_vec();
     let mut c02_scrubber_values = input.to_vec();
@@ -55,19 +71,44 @@ pub fn get_life_support_rating(input: &[String]) -> u32 {
         }
     }
 
-    if oxygen_generator_values.len() != 1 {
-        panic!("Too many oxygen values!: {:?}", oxygen_generator_values);
-    } else if c02_scrubber_values.len() != 1 {
-        panic!("Too many c02 values!: {:?}", c02_scrubber_values);
-    }
+    let oxygen_generator_rating = match oxygen_generator_values.as_slice() {
+        [oxygen_generator_rating_str] => binary_to_decimal(oxygen_generator_rating_str)?,
+        _ => {
+            return Err(format!(
+                "Too many oxygen values!: {:?}",
+                oxygen_generator_values
+            ))
+        }
+    };
 
-    let oxygen_generator_rating_str = oxygen_generator_values.get(0).unwrap();
-    let oxygen_generator_rating = u32::from_str_radix(oxygen_generator_rating_str, 2).unwrap();
+    let c02_scrubber_rating = match c02_scrubber_values.as_slice() {
+        [c02_scrubber_rating_str] => binary_to_decimal(c02_scrubber_rating_str)?,
+        _ => return Err(format!("Too many c02 values!: {:?}", c02_scrubber_values)),
+    };
 
-    let c02_scrubber_rating_str = c02_scrubber_values.get(0).unwrap();
-    let c02_scrubber_rating = u32::from_str_radix(c02_scrubber_rating_str, 2).unwrap();
+    Ok(oxygen_generator_rating * c02_scrubber_rating)
+}
+
+fn get_length_of_binary_strings(input: &[String]) -> Result<usize, String> {
+    let length_of_binary_string = input
+        .first()
+        .map(|bit_string| bit_string.len())
+        .ok_or_else(|| String::from("Diagnostic report is empty!"))?;
+
+    match input.iter().find(|bit_string| {
+        (bit_string.len() != length_of_binary_string)
+            || bit_string.contains(|bit_char| bit_char != '0' && bit_char != '1')
+    }) {
+        Some(bit_string) => Err(format!(
+            "Expected {} bits in bit_string {}",
+            length_of_binary_string, bit_string
+        )),
+        None => Ok(length_of_binary_string),
+    }
+}
 
-    oxygen_generator_rating * c02_scrubber_rating
+fn binary_to_decimal(binary: &str) -> Result<u32, String> {
+    u32::from_str_radix(binary, 2).map_err(|error| format!("{}: {}", binary, error))
 }
 
 fn get_most_signifcant_bit_at(input: &[String], column: usize) -> u32 {
@@ -75,9 +116,8 @@ fn get_most_signifcant_bit_at(input: &[String], column: usize) -> u32 {
 
     for bit_string in input {
         match bit_string.chars().nth(column) {
-            Some('0') => counter -= 1,
             Some('1') => counter += 1,
-            _ => panic!("Column {} too large for bit_string {}", column, bit_string),
+            _ => counter -= 1,
         }
     }
 
@@ -89,27 +129,18 @@ fn get_most_signifcant_bit_at(input: &[String], column: usize) -> u32 {
 }
 
 fn get_least_signifcant_bit_at(input: &[String], column: usize) -> u32 {
-    let most_signifcant_bit = get_most_signifcant_bit_at(input, column);
-
-    match most_signifcant_bit {
-        0 => 1,
-        1 => 0,
-        _ => panic!("Unknown bit: {}", most_signifcant_bit),
-    }
+    1 - get_most_signifcant_bit_at(input, column)
 }
 
 fn filter_for_number_with_bits_at(input: &[String], bit: u32, column: usize) -> Vec<String> {
     input
         .iter()
         .filter(|binary| {
-            let binary_char = binary
+            binary
                 .chars()
                 .nth(column)
-                .expect(&format!("Column too big: {}", column))
-                .to_digit(10)
-                .expect(&format!("Unknown char: {}", binary));
-
-            binary_char == bit
+                .and_then(|binary_char| binary_char.to_digit(2))
+                == Some(bit)
         })
         .map(|s| s.to_string())
         .collect()
@@ -137,38 +168,72 @@ fn array_of_bits_to_decimal(bits: &[u32]) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_3::EXAMPLES;
 
-    const TEST_DATA: [&str; 12] = [
-        "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000", "11001",
-        "00010", "01010",
-    ];
     const COUNTED_BITS: [i32; 5] = [2, -3, 4, 2, -2];
 
+    #[test]
+    fn test_get_diagnostic_report() {
+        let input = EXAMPLES[0].get_lines();
+
+        assert_eq!(get_diagnostic_report(&input), Ok(input.clone()));
+
+        let bad_bit: Vec<String> = ["00100", "11201"].iter().map(|s| s.to_string()).collect();
+        let bad_length: Vec<String> = ["00100", "111"].iter().map(|s| s.to_string()).collect();
+
+        assert_eq!(
+            get_diagnostic_report(&bad_bit).unwrap_err().to_string(),
+            "2:3: expected a binary number"
+        );
+        assert_eq!(
+            get_diagnostic_report(&bad_length).unwrap_err().to_string(),
+            "2: expected 5 bits"
+        );
+    }
+
     #[test]
     fn test_get_power_consumption() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
-        let expected = 198;
+        let expected = Ok(198);
 
         let result = get_power_consumption(&input);
 
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_get_power_consumption_error() {
+        let empty: Vec<String> = Vec::new();
+        let bad_length: Vec<String> = ["00100", "111"].iter().map(|s| s.to_string()).collect();
+
+        assert!(get_power_consumption(&empty).is_err());
+        assert!(get_power_consumption(&bad_length).is_err());
+    }
+
     #[test]
     fn test_get_life_support_rating() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
-        let expected = 230;
+        let expected = Ok(230);
 
         let result = get_life_support_rating(&input);
 
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_get_life_support_rating_error() {
+        let duplicates: Vec<String> = ["101", "101"].iter().map(|s| s.to_string()).collect();
+        let bad_bit: Vec<String> = ["101", "121"].iter().map(|s| s.to_string()).collect();
+
+        assert!(get_life_support_rating(&duplicates).is_err());
+        assert!(get_life_support_rating(&bad_bit).is_err());
+    }
+
     #[test]
     fn test_get_most_signifcant_bit_at() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
         let expected_1 = 1;
         let expected_2 = 0;
@@ -182,7 +247,7 @@ mod tests {
 
     #[test]
     fn test_get_least_signifcant_bit_at() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
         let expected_1 = 0;
         let expected_2 = 1;
@@ -196,7 +261,7 @@ mod tests {
 
     #[test]
     fn test_filter_for_number_with_bits_at() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
         let expected_1 = vec![
             "11110", "10110", "10111", "10101", "11100", "10000", "11001",
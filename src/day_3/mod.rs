@@ -1,13 +1,47 @@
 mod binary_diagnostic;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use binary_diagnostic::{
+    get_diagnostic_report, get_life_support_rating, get_power_consumption,
+};
 
-pub fn run_day_3() {
-    let file_input = to_string_vector("inputs/day_3.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010",
+    part_one: Some("198"),
+    part_two: Some("230"),
+}];
 
-    let part_1_result = binary_diagnostic::get_power_consumption(&file_input);
-    let part_2_result = binary_diagnostic::get_life_support_rating(&file_input);
+pub struct Day3;
 
-    println!("Day 3 Part 1: {}", part_1_result);
-    println!("Day 3 Part 2: {}", part_2_result);
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &[String]) -> Result<Vec<String>, ParseError> {
+        binary_diagnostic::get_diagnostic_report(input)
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<Answer, String> {
+        binary_diagnostic::get_power_consumption(input).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> Result<Answer, String> {
+        binary_diagnostic::get_life_support_rating(input).map(Answer::from)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -1,7 +1,10 @@
+use crate::util::parse_error::{parse_number, ParseError};
 use crate::util::point_2d::Point2d;
 use std::collections::{HashMap, HashSet};
 
-#[derive(Debug, PartialEq)]
+const BOARD_SIZE: usize = 5;
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct BingoBoard {
     numbers_to_locations: HashMap<u32, Point2d<u32>>,
     marked_columns: HashMap<u32, HashSet<u32>>,
@@ -11,25 +14,6 @@ pub struct BingoBoard {
 }
 
 impl BingoBoard {
-    pub fn new(input: &[String]) -> BingoBoard {
-        let int_input: Vec<Vec<u32>> = input
-            .iter()
-            .filter(|s| !s.is_empty())
-            .map(|s| {
-                s.split(' ')
-                    .filter(|split| !split.is_empty())
-                    .map(|split| {
-                        split
-                            .parse::<u32>()
-                            .expect(&format!("Cannot parse {} to a u32", split))
-                    })
-                    .collect()
-            })
-            .collect();
-
-        Self::new_from_ints(&int_input)
-    }
-
     pub fn new_from_ints(input: &[Vec<u32>]) -> BingoBoard {
         let mut numbers_to_locations: HashMap<u32, Point2d<u32>> = HashMap::new();
 
@@ -66,14 +50,16 @@ impl BingoBoard {
                 self.marked_columns.entry(column).or_insert(HashSet::new());
             let marked_numbers_in_row = self.marked_rows.entry(row).or_insert(HashSet::new());
 
-            marked_numbers_in_column.insert(called_number);
+            let newly_marked = marked_numbers_in_column.insert(called_number);
             marked_numbers_in_row.insert(called_number);
 
             if (marked_numbers_in_column.len() == 5) || (marked_numbers_in_row.len() == 5) {
                 self.winning_number = Some(called_number);
             }
 
-            self.sum_of_uncalled_numbers -= called_number;
+            if newly_marked {
+                self.sum_of_uncalled_numbers -= called_number;
+            }
         }
     }
 
@@ -87,6 +73,45 @@ impl BingoBoard {
     }
 }
 
+impl TryFrom<&[String]> for BingoBoard {
+    type Error = ParseError;
+
+    fn try_from(input: &[String]) -> Result<BingoBoard, ParseError> {
+        if input.len() != BOARD_SIZE {
+            return Err(ParseError::new(
+                input.last().map_or("", |line| line.as_str()),
+                &format!("expected {} rows, but got {}", BOARD_SIZE, input.len()),
+            )
+            .at_line(input.len()));
+        }
+
+        let int_input = input
+            .iter()
+            .enumerate()
+            .map(|(row_index, row)| {
+                let numbers = row
+                    .split(' ')
+                    .filter(|split| !split.is_empty())
+                    .map(parse_number)
+                    .collect::<Result<Vec<u32>, ParseError>>()
+                    .map_err(|error| error.at_line(row_index + 1))?;
+
+                if numbers.len() != BOARD_SIZE {
+                    return Err(ParseError::new(
+                        row,
+                        &format!("expected {} numbers, but got {}", BOARD_SIZE, numbers.len()),
+                    )
+                    .at_line(row_index + 1));
+                }
+
+                Ok(numbers)
+            })
+            .collect::<Result<Vec<Vec<u32>>, ParseError>>()?;
+
+        Ok(Self::new_from_ints(&int_input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,12 +127,38 @@ mod tests {
     ];
 
     #[test]
-    fn test_new() {
+    fn test_try_from() {
         let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
 
         let expected = get_test_board();
 
-        let result = BingoBoard::new(&input);
+        let result = BingoBoard::try_from(&input[..5]);
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_try_from_errors() {
+        let expected = vec![
+            "3: expected a number, but got '2e'",
+            "2: expected 5 numbers, but got 4",
+            "4: expected 5 rows, but got 4",
+        ];
+
+        let row = "14 21 17 24  4";
+
+        let result: Vec<String> = [
+            vec![row, row, "18  8 2e 26 20", row, row],
+            vec![row, "10 16 15  9", row, row, row],
+            vec![row; 4],
+        ]
+        .iter()
+        .map(|lines| {
+            let input: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
+
+            BingoBoard::try_from(&input[..]).unwrap_err().to_string()
+        })
+        .collect();
 
         assert_eq!(result, expected);
     }
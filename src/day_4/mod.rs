@@ -1,28 +1,89 @@
 mod bingo;
 
-use crate::util::file_reader::to_string_vector;
-use bingo::BingoBoard;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::file_reader::{get_comma_separated_numbers, get_indexed_sections};
+use crate::util::parse_error::ParseError;
+pub use bingo::BingoBoard;
 use std::collections::HashSet;
 
-pub fn run_day_4() {
-    let file_input = to_string_vector("inputs/day_4.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7",
+    part_one: Some("4512"),
+    part_two: Some("1924"),
+}];
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BingoGame {
+    called_numbers: Vec<u32>,
+    boards: Vec<BingoBoard>,
+}
 
-    let scores_in_order = get_board_scores_in_winning_order(&file_input);
+impl TryFrom<&[String]> for BingoGame {
+    type Error = ParseError;
 
-    println!("Day 4 Part 1: {}", scores_in_order.get(0).unwrap());
-    println!("Day 4 Part 2: {}", scores_in_order.last().unwrap());
+    fn try_from(file_input: &[String]) -> Result<BingoGame, ParseError> {
+        Ok(BingoGame {
+            called_numbers: get_called_numbers(file_input)?,
+            boards: get_boards(file_input)?,
+        })
+    }
 }
 
-fn get_board_scores_in_winning_order(file_input: &[String]) -> Vec<u32> {
-    let mut scores = Vec::new();
+pub struct Day4;
 
-    let called_numbers = get_called_numbers(&file_input);
+impl Solution for Day4 {
+    type Input = BingoGame;
+
+    fn parse(&self, input: &[String]) -> Result<BingoGame, ParseError> {
+        BingoGame::try_from(input)
+    }
+
+    fn part_one(&self, game: &BingoGame) -> Result<Answer, String> {
+        get_board_scores_in_winning_order(game)
+            .first()
+            .map(|&score| score.into())
+            .ok_or_else(|| String::from("No board ever won!"))
+    }
+
+    fn part_two(&self, game: &BingoGame) -> Result<Answer, String> {
+        get_board_scores_in_winning_order(game)
+            .last()
+            .map(|&score| score.into())
+            .ok_or_else(|| String::from("No board ever won!"))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
+}
+
+fn get_board_scores_in_winning_order(game: &BingoGame) -> Vec<u32> {
+    let mut scores = Vec::new();
 
-    let mut boards = get_boards(&file_input);
+    let mut boards = game.boards.clone();
 
     let mut won_boards = HashSet::new();
 
-    for number in called_numbers {
+    for &number in game.called_numbers.iter() {
         for (board_index, board) in boards.iter_mut().enumerate() {
             if won_boards.contains(&board_index) {
                 continue;
@@ -30,8 +91,8 @@ fn get_board_scores_in_winning_order(file_input: &[String]) -> Vec<u32> {
 
             board.mark_number(number);
 
-            if board.is_won() {
-                scores.push(board.get_score().expect("Won without score?"));
+            if let Some(score) = board.get_score() {
+                scores.push(score);
                 won_boards.insert(board_index);
             }
         }
@@ -40,64 +101,80 @@ fn get_board_scores_in_winning_order(file_input: &[String]) -> Vec<u32> {
     scores
 }
 
-fn get_called_numbers(file_input: &[String]) -> Vec<u32> {
-    file_input
-        .get(0)
-        .expect("File input is empty!!!")
-        .split(',')
-        .map(|split| {
-            split
-                .parse()
-                .expect(&format!("Cannot parse {} into u32", split))
-        })
-        .collect()
-}
-
-fn get_boards(file_input: &[String]) -> Vec<BingoBoard> {
-    let mut boards = Vec::new();
+fn get_called_numbers(file_input: &[String]) -> Result<Vec<u32>, ParseError> {
+    let first_line = file_input
+        .first()
+        .ok_or_else(|| ParseError::new("", "expected a line of called numbers"))?;
 
-    let skip_head_slice = &file_input[2..];
+    get_comma_separated_numbers(first_line).map_err(|error| error.at_line(1))
+}
 
-    for chunk in skip_head_slice.chunks(6) {
-        boards.push(BingoBoard::new(chunk));
+fn get_boards(file_input: &[String]) -> Result<Vec<BingoBoard>, ParseError> {
+    match file_input.get(1) {
+        Some(separator) if separator.trim().is_empty() => (),
+        Some(separator) => {
+            return Err(ParseError::new(
+                separator,
+                &format!("expected a blank line, but got '{}'", separator),
+            )
+            .at_line(2))
+        }
+        None => return Err(ParseError::new("", "expected a blank line").at_line(2)),
     }
 
-    boards
+    get_indexed_sections(&file_input[2..])
+        .into_iter()
+        .map(|(line_index, section)| {
+            BingoBoard::try_from(section).map_err(|error| error.offset_lines(line_index + 2))
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_DATA: [&str; 19] = [
-        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1",
-        "",
-        "22 13 17 11  0",
-        " 8  2 23  4 24",
-        "21  9 14 16  7",
-        " 6 10  3 18  5",
-        " 1 12 20 15 19",
-        "",
-        " 3 15  0  2 22",
-        " 9 18 13 17  5",
-        "19  8  7 25 23",
-        "20 11 10 24  4",
-        "14 21 16 12  6",
-        "",
-        "14 21 17 24  4",
-        "10 16 15  9 19",
-        "18  8 23 26 20",
-        "22 11 13  6  5",
-        " 2  0 12  3  7",
-    ];
-
     #[test]
     fn test_get_board_scores_in_winning_order() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
         let expected = vec![4512, 2192, 1924];
 
-        let result = get_board_scores_in_winning_order(&input);
+        let result = get_board_scores_in_winning_order(&BingoGame::try_from(&input[..]).unwrap());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bingo_game_try_from_error() {
+        let board = [
+            "22 13 17 11  0",
+            " 8  2 23  4 24",
+            "21  9 14 16  7",
+            " 6 10  3 18  5",
+            " 1 12 20 15 19",
+        ];
+
+        let expected = vec![
+            "1:5: expected a number, but got 'x'",
+            "2: expected a blank line, but got '22 13 17 11  0'",
+            "2: expected a blank line",
+            "9: expected 5 rows, but got 1",
+        ];
+
+        let result: Vec<String> = [
+            [&["7,4,x", ""][..], &board].concat(),
+            [&["7,4,9"][..], &board].concat(),
+            vec!["7,4,9"],
+            [&["7,4,9", ""][..], &board, &["", "14 21 17 24  4"]].concat(),
+        ]
+        .iter()
+        .map(|lines| {
+            let input: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
+
+            BingoGame::try_from(&input[..]).unwrap_err().to_string()
+        })
+        .collect();
 
         assert_eq!(result, expected);
     }
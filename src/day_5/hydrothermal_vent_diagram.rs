@@ -1,131 +1,149 @@
-use crate::util::line_2d::Line2d;
+use crate::util::line_2d::{Line2d, LineIntersection};
+use crate::util::parse_error::{parse_lines_with, ParseError};
 use crate::util::point_2d::Point2d;
-use std::collections::HashMap;
+use std::collections::HashSet;
+
+const LINE_FORMAT: &str = "x1,y1 -> x2,y2";
 
 pub struct HydrothermalVentDiagram {
-    diagram: HashMap<Point2d<i32>, u32>,
-    number_of_overlapping_vents: u32,
+    lines: Vec<Line2d>,
+    overlapping_vents: HashSet<Point2d<i32>>,
+}
+
+impl Default for HydrothermalVentDiagram {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl HydrothermalVentDiagram {
     pub fn new() -> HydrothermalVentDiagram {
         HydrothermalVentDiagram {
-            diagram: HashMap::new(),
-            number_of_overlapping_vents: 0,
+            lines: Vec::new(),
+            overlapping_vents: HashSet::new(),
         }
     }
 
-    pub fn add_lines<P>(&mut self, lines: &[String], predicate: P)
+    pub fn get_lines(input: &[String]) -> Result<Vec<Line2d>, ParseError> {
+        parse_lines_with(input, Self::get_line)
+    }
+
+    pub fn add_lines<P>(&mut self, lines: &[Line2d], mut predicate: P)
     where
         P: FnMut(&Line2d) -> bool,
     {
         lines
             .iter()
-            .map(|line_str| Self::get_line(line_str))
-            .filter(predicate)
-            .for_each(|line| self.add_line(line));
+            .filter(|line| predicate(line))
+            .for_each(|&line| self.add_line(line));
     }
 
     pub fn get_number_of_overlapping_vents(&self) -> u32 {
-        self.number_of_overlapping_vents
+        self.overlapping_vents.len() as u32
     }
 
     fn add_line(&mut self, line: Line2d) {
-        for point in line.int_points_along_line() {
-            let entry = self
-                .diagram
-                .entry(point)
-                .and_modify(|x| *x += 1)
-                .or_insert(1);
-
-            if *entry == 2 {
-                self.number_of_overlapping_vents += 1;
+        for other_line in self.lines.iter() {
+            match line.get_int_intersection(other_line) {
+                Some(LineIntersection::Point(point)) => {
+                    self.overlapping_vents.insert(point);
+                }
+                Some(LineIntersection::Segment(segment)) => {
+                    self.overlapping_vents.extend(segment.get_lattice_points());
+                }
+                None => {}
             }
         }
-    }
-
-    fn get_line(description: &str) -> Line2d {
-        let (start_str, end_str) = description
-            .split_once(" -> ")
-            .expect(&format!("Could not split: {}", description));
 
-        let start = Self::get_point(start_str);
-        let end = Self::get_point(end_str);
-
-        Line2d::new((start.x, start.y), (end.x, end.y))
+        self.lines.push(line);
     }
 
-    fn get_point(description: &str) -> Point2d<i32> {
-        let parse_error = |str_value| format!("Could not parse: {}", str_value);
+    fn get_line(description: &str) -> Result<Line2d, ParseError> {
+        let expected_line = || ParseError::expected(description, LINE_FORMAT);
 
-        let (x_str, y_str) = description
-            .split_once(',')
-            .expect(&format!("Could not split: {}", description));
+        let (start_str, end_str) = description.split_once(" -> ").ok_or_else(expected_line)?;
 
-        let x = x_str.parse().expect(&parse_error(x_str));
-        let y = y_str.parse().expect(&parse_error(y_str));
+        let start: Point2d<i32> = start_str.parse().map_err(|_| expected_line())?;
+        let end: Point2d<i32> = end_str.parse().map_err(|_| expected_line())?;
 
-        Point2d::new(x, y)
+        Ok(Line2d::new((start.x, start.y), (end.x, end.y)))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_5::EXAMPLES;
+
+    fn get_test_lines() -> Vec<Line2d> {
+        let input = EXAMPLES[0].get_lines();
 
-    const TEST_DATA: [&str; 10] = [
-        "0,9 -> 5,9",
-        "8,0 -> 0,8",
-        "9,4 -> 3,4",
-        "2,2 -> 2,1",
-        "7,0 -> 7,4",
-        "6,4 -> 2,0",
-        "0,9 -> 2,9",
-        "3,4 -> 1,4",
-        "0,0 -> 8,8",
-        "5,5 -> 8,2",
-    ];
+        HydrothermalVentDiagram::get_lines(&input).unwrap()
+    }
 
     #[test]
     fn test_add_lines_all() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = get_test_lines();
 
         let mut test_diagram = HydrothermalVentDiagram::new();
 
         test_diagram.add_lines(&input, |_| true);
 
-        let expected_map_size = 39;
-        let expected_number_of_overlapping_vents = 12;
+        let expected = 12;
 
-        let result_map_size = test_diagram.diagram.len();
-        let result_number_of_overlapping_vents = test_diagram.number_of_overlapping_vents;
+        let result = test_diagram.get_number_of_overlapping_vents();
 
-        assert_eq!(result_map_size, expected_map_size);
-        assert_eq!(
-            result_number_of_overlapping_vents,
-            expected_number_of_overlapping_vents
-        );
+        assert_eq!(result, expected);
     }
 
     #[test]
     fn test_add_lines_vertical_or_horizontal_only() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = get_test_lines();
 
         let mut test_diagram = HydrothermalVentDiagram::new();
 
         test_diagram.add_lines(&input, |line| line.is_vertical() || line.is_horizontal());
 
-        let expected_map_size = 21;
-        let expected_number_of_overlapping_vents = 5;
+        let expected = 5;
 
-        let result_map_size = test_diagram.diagram.len();
-        let result_number_of_overlapping_vents = test_diagram.number_of_overlapping_vents;
+        let result = test_diagram.get_number_of_overlapping_vents();
 
-        assert_eq!(result_map_size, expected_map_size);
-        assert_eq!(
-            result_number_of_overlapping_vents,
-            expected_number_of_overlapping_vents
-        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_lines_arbitrary_slopes() {
+        let collinear = vec![Line2d::new((0, 0), (6, 3)), Line2d::new((8, 4), (2, 1))];
+        let crossing_between_points =
+            vec![Line2d::new((0, 5), (1, 6)), Line2d::new((1, 5), (0, 6))];
+
+        let mut collinear_diagram = HydrothermalVentDiagram::new();
+        let mut crossing_diagram = HydrothermalVentDiagram::new();
+
+        collinear_diagram.add_lines(&collinear, |_| true);
+        crossing_diagram.add_lines(&crossing_between_points, |_| true);
+
+        let expected_collinear =
+            HashSet::from([Point2d::new(2, 1), Point2d::new(4, 2), Point2d::new(6, 3)]);
+
+        assert_eq!(collinear_diagram.overlapping_vents, expected_collinear);
+        assert_eq!(collinear_diagram.get_number_of_overlapping_vents(), 3);
+        assert_eq!(crossing_diagram.get_number_of_overlapping_vents(), 0);
+    }
+
+    #[test]
+    fn test_add_lines_counts_lattice_points_not_raster_points() {
+        let lines = vec![Line2d::new((0, 0), (5, 2)), Line2d::new((1, 0), (1, 3))];
+
+        let mut test_diagram = HydrothermalVentDiagram::new();
+
+        test_diagram.add_lines(&lines, |_| true);
+
+        assert!(lines[0]
+            .int_points_along_line()
+            .contains(&Point2d::new(1, 0)));
+        assert!(!lines[0].get_lattice_points().contains(&Point2d::new(1, 0)));
+        assert_eq!(test_diagram.get_number_of_overlapping_vents(), 0);
     }
 
     #[test]
@@ -136,16 +154,21 @@ mod tests {
 
         let result = HydrothermalVentDiagram::get_line(input);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
-    fn test_get_point() {
-        let input = "8,2";
+    fn test_get_lines_error() {
+        let input: Vec<String> = ["0,9 -> 5,9", "8,0 => 0,8"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
 
-        let expected = Point2d::new(8, 2);
+        let expected = "inputs/day_5.txt:2: expected 'x1,y1 -> x2,y2'";
 
-        let result = HydrothermalVentDiagram::get_point(input);
+        let result = HydrothermalVentDiagram::get_lines(&input)
+            .unwrap_err()
+            .in_file("inputs/day_5.txt");
 
         assert_eq!(result, expected);
     }
@@ -1,25 +1,52 @@
 mod hydrothermal_vent_diagram;
 
-use crate::util::file_reader::to_string_vector;
-use hydrothermal_vent_diagram::HydrothermalVentDiagram;
-
-pub fn run_day_5() {
-    let file_input = to_string_vector("inputs/day_5.txt").unwrap();
-
-    let mut diagram = HydrothermalVentDiagram::new();
-
-    diagram.add_lines(&file_input, |line| {
-        line.is_vertical() || line.is_horizontal()
-    });
-
-    let part_1_result = diagram.get_number_of_overlapping_vents();
-
-    diagram = HydrothermalVentDiagram::new();
-
-    diagram.add_lines(&file_input, |_| true);
-
-    let part_2_result = diagram.get_number_of_overlapping_vents();
-
-    println!("Day 5 Part 1: {}", part_1_result);
-    println!("Day 5 Part 2: {}", part_2_result);
+use crate::solution::{Answer, Example, Solution};
+use crate::util::line_2d::Line2d;
+use crate::util::parse_error::ParseError;
+pub use hydrothermal_vent_diagram::HydrothermalVentDiagram;
+
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2",
+    part_one: Some("5"),
+    part_two: Some("12"),
+}];
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line2d>;
+
+    fn parse(&self, input: &[String]) -> Result<Vec<Line2d>, ParseError> {
+        HydrothermalVentDiagram::get_lines(input)
+    }
+
+    fn part_one(&self, lines: &Vec<Line2d>) -> Result<Answer, String> {
+        let mut diagram = HydrothermalVentDiagram::new();
+
+        diagram.add_lines(lines, |line| line.is_vertical() || line.is_horizontal());
+
+        Ok(diagram.get_number_of_overlapping_vents().into())
+    }
+
+    fn part_two(&self, lines: &Vec<Line2d>) -> Result<Answer, String> {
+        let mut diagram = HydrothermalVentDiagram::new();
+
+        diagram.add_lines(lines, |_| true);
+
+        Ok(diagram.get_number_of_overlapping_vents().into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
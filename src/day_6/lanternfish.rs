@@ -1,3 +1,5 @@
+use crate::util::file_reader::get_comma_separated_numbers;
+use crate::util::parse_error::ParseError;
 use std::collections::HashMap;
 
 const BIRTH_COOLDOWN: i32 = 2;
@@ -66,23 +68,20 @@ pub fn get_lanternfish_population_created_in_days(initial_fish: &[LanternFish],
     result
 }
 
-pub fn get_initial_fish(input: &str) -> Vec<LanternFish> {
-    input
-        .split(',')
-        .map(|split| {
-            split
-                .parse()
-                .expect(&format!("Could not parse u64: {}", split))
-        })
-        .map(|days_until_spawn| LanternFish::from_days_until_spawn(days_until_spawn))
-        .collect()
+pub fn get_initial_fish(input: &str) -> Result<Vec<LanternFish>, ParseError> {
+    let days_until_spawn = get_comma_separated_numbers(input)?;
+
+    Ok(days_until_spawn
+        .into_iter()
+        .map(LanternFish::from_days_until_spawn)
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_6::EXAMPLES;
 
-    const TEST_DATA_STR: &str = "3,4,3,1,2";
     const TEST_DATA: [i32; 5] = [3, 4, 3, 1, 2];
 
     #[test]
@@ -161,8 +160,9 @@ mod tests {
             .map(|&days_until_spawn| LanternFish::from_days_until_spawn(days_until_spawn))
             .collect();
 
-        let result = get_initial_fish(TEST_DATA_STR);
+        let result = get_initial_fish(EXAMPLES[0].input);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
+        assert!(get_initial_fish("3,4,-").is_err());
     }
 }
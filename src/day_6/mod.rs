@@ -1,16 +1,35 @@
 mod lanternfish;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::file_reader::get_single_line;
+use crate::util::parse_error::ParseError;
+pub use lanternfish::{get_initial_fish, get_lanternfish_population_created_in_days, LanternFish};
 
-pub fn run_day_6() {
-    let file_input = to_string_vector("inputs/day_6.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "3,4,3,1,2",
+    part_one: Some("5934"),
+    part_two: Some("26984457539"),
+}];
 
-    let initial_fish =
-        lanternfish::get_initial_fish(&file_input.get(0).expect(&format!("Input is empty!")));
+pub struct Day6;
 
-    let part_1_result = lanternfish::get_lanternfish_population_created_in_days(&initial_fish, 80);
-    let part_2_result = lanternfish::get_lanternfish_population_created_in_days(&initial_fish, 256);
+impl Solution for Day6 {
+    type Input = Vec<LanternFish>;
 
-    println!("Day 6 Part 1: {}", part_1_result);
-    println!("Day 6 Part 2: {}", part_2_result);
+    fn parse(&self, input: &[String]) -> Result<Vec<LanternFish>, ParseError> {
+        get_single_line(input)
+            .and_then(|line| lanternfish::get_initial_fish(line).map_err(|error| error.at_line(1)))
+    }
+
+    fn part_one(&self, initial_fish: &Vec<LanternFish>) -> Result<Answer, String> {
+        Ok(lanternfish::get_lanternfish_population_created_in_days(initial_fish, 80).into())
+    }
+
+    fn part_two(&self, initial_fish: &Vec<LanternFish>) -> Result<Answer, String> {
+        Ok(lanternfish::get_lanternfish_population_created_in_days(initial_fish, 256).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -1,3 +1,7 @@
+use crate::util::file_reader::get_comma_separated_numbers;
+use crate::util::parse_error::{parse_number, ParseError};
+use std::str::FromStr;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CrabSubmarine {
     x_position: i32,
@@ -23,15 +27,13 @@ impl CrabSubmarine {
     }
 }
 
-pub fn get_crab_submarines(input: &str) -> Vec<CrabSubmarine> {
-    input
-        .split(',')
-        .filter(|s| !s.is_empty())
-        .map(|s| CrabSubmarine::from(s))
-        .collect()
+pub fn get_crab_submarines(input: &str) -> Result<Vec<CrabSubmarine>, ParseError> {
+    let x_positions = get_comma_separated_numbers(input.trim_end_matches(','))?;
+
+    Ok(x_positions.into_iter().map(CrabSubmarine::new).collect())
 }
 
-pub fn minimum_fuel_to_align_v1(crab_submarines: &[CrabSubmarine]) -> i32 {
+pub fn minimum_fuel_to_align_v1(crab_submarines: &[CrabSubmarine]) -> Result<i32, String> {
     let mut submarines_sorted = crab_submarines.to_vec();
 
     submarines_sorted.sort();
@@ -40,15 +42,12 @@ pub fn minimum_fuel_to_align_v1(crab_submarines: &[CrabSubmarine]) -> i32 {
 
     let median_value = submarines_sorted
         .get(median_index)
-        .expect(&format!(
-            "Could not get median index of: {:?}",
-            submarines_sorted
-        ))
+        .ok_or_else(|| String::from("No crab submarines to align"))?
         .get_x_position();
 
-    submarines_sorted
+    Ok(submarines_sorted
         .iter()
-        .fold(0, |acc, sub| acc + sub.linear_distance_to(median_value))
+        .fold(0, |acc, sub| acc + sub.linear_distance_to(median_value)))
 }
 
 pub fn minimum_fuel_to_align_v2(crab_submarines: &[CrabSubmarine]) -> i32 {
@@ -90,39 +89,27 @@ pub fn minimum_fuel_to_align_v2(crab_submarines: &[CrabSubmarine]) -> i32 {
     result
 }
 
-impl From<&str> for CrabSubmarine {
-    fn from(input: &str) -> CrabSubmarine {
-        let x_position = input
-            .parse()
-            .expect(&format!("Could not parse into i32: {}", input));
-
-        CrabSubmarine::new(x_position)
-    }
-}
-
-impl From<&String> for CrabSubmarine {
-    fn from(input: &String) -> CrabSubmarine {
-        let x_position = input
-            .parse()
-            .expect(&format!("Could not parse into i32: {}", input));
+impl FromStr for CrabSubmarine {
+    type Err = ParseError;
 
-        CrabSubmarine::new(x_position)
+    fn from_str(input: &str) -> Result<CrabSubmarine, ParseError> {
+        parse_number(input).map(CrabSubmarine::new)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "16,1,2,0,4,2,7,1,2,14";
+    use crate::day_7::EXAMPLES;
 
     #[test]
     fn test_crab_submarine_from_str() {
         let expected = CrabSubmarine::new(16);
 
-        let result = CrabSubmarine::from("16");
+        let result = "16".parse();
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
+        assert!("1 6".parse::<CrabSubmarine>().is_err());
     }
 
     #[test]
@@ -207,25 +194,30 @@ mod tests {
             CrabSubmarine::new(14),
         ];
 
-        let result = get_crab_submarines(TEST_DATA);
+        let result = get_crab_submarines(EXAMPLES[0].input);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
+        assert_eq!(
+            get_crab_submarines("16,1,x").map_err(|error| error.column),
+            Err(Some(6))
+        );
     }
 
     #[test]
     fn test_minimum_fuel_to_align_v1() {
-        let submarines = get_crab_submarines(TEST_DATA);
+        let submarines = get_crab_submarines(EXAMPLES[0].input).unwrap();
 
         let expected = 37;
 
         let result = minimum_fuel_to_align_v1(&submarines);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
+        assert!(minimum_fuel_to_align_v1(&[]).is_err());
     }
 
     #[test]
     fn test_minimum_fuel_to_align_v2() {
-        let submarines = get_crab_submarines(TEST_DATA);
+        let submarines = get_crab_submarines(EXAMPLES[0].input).unwrap();
 
         let expected = 168;
 
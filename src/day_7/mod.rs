@@ -1,17 +1,38 @@
 mod crab_submarine;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::file_reader::get_single_line;
+use crate::util::parse_error::ParseError;
+pub use crab_submarine::{
+    get_crab_submarines, minimum_fuel_to_align_v1, minimum_fuel_to_align_v2, CrabSubmarine,
+};
 
-pub fn run_day_7() {
-    let file_input = to_string_vector("inputs/day_7.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "16,1,2,0,4,2,7,1,2,14",
+    part_one: Some("37"),
+    part_two: Some("168"),
+}];
 
-    let crab_submarines = crab_submarine::get_crab_submarines(
-        file_input.get(0).expect(&format!("File input empty!")),
-    );
+pub struct Day7;
 
-    let part_1_result = crab_submarine::minimum_fuel_to_align_v1(&crab_submarines);
-    let part_2_result = crab_submarine::minimum_fuel_to_align_v2(&crab_submarines);
+impl Solution for Day7 {
+    type Input = Vec<CrabSubmarine>;
 
-    println!("Day 7 Part 1: {}", part_1_result);
-    println!("Day 7 Part 2: {}", part_2_result);
+    fn parse(&self, input: &[String]) -> Result<Vec<CrabSubmarine>, ParseError> {
+        get_single_line(input).and_then(|line| {
+            crab_submarine::get_crab_submarines(line).map_err(|error| error.at_line(1))
+        })
+    }
+
+    fn part_one(&self, crab_submarines: &Vec<CrabSubmarine>) -> Result<Answer, String> {
+        crab_submarine::minimum_fuel_to_align_v1(crab_submarines).map(Answer::from)
+    }
+
+    fn part_two(&self, crab_submarines: &Vec<CrabSubmarine>) -> Result<Answer, String> {
+        Ok(crab_submarine::minimum_fuel_to_align_v2(crab_submarines).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
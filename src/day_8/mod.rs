@@ -1,15 +1,49 @@
 mod seven_segment_display;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use seven_segment_display::{
+    get_displays_for, get_entries, get_number_of_1_4_7_or_8_displays, Display, Entry, Wire,
+};
 
-pub fn run_day_8() {
-    let file_input = to_string_vector("inputs/day_8.txt").unwrap();
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+    part_one: Some("26"),
+    part_two: Some("61229"),
+}];
 
-    let part_1_result = seven_segment_display::get_number_of_1_4_7_or_8_displays(&file_input);
-    let part_2_result: u32 = seven_segment_display::get_displays_for(&file_input)
-        .iter()
-        .sum();
+pub struct Day8;
 
-    println!("Day 8 Part 1: {}", part_1_result);
-    println!("Day 8 Part 2: {}", part_2_result);
+impl Solution for Day8 {
+    type Input = Vec<Entry>;
+
+    fn parse(&self, input: &[String]) -> Result<Vec<Entry>, ParseError> {
+        seven_segment_display::get_entries(input)
+    }
+
+    fn part_one(&self, entries: &Vec<Entry>) -> Result<Answer, String> {
+        Ok(seven_segment_display::get_number_of_1_4_7_or_8_displays(entries).into())
+    }
+
+    fn part_two(&self, entries: &Vec<Entry>) -> Result<Answer, String> {
+        let result: u32 = seven_segment_display::get_displays_for(entries)?
+            .iter()
+            .sum();
+
+        Ok(result.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -1,4 +1,8 @@
+use crate::util::parse_error::{parse_lines, ParseError};
 use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+const ENTRY_FORMAT: &str = "10 patterns | 4 digits";
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum Segment {
@@ -12,7 +16,7 @@ enum Segment {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Wire {
+pub enum Wire {
     A,
     B,
     C,
@@ -22,18 +26,48 @@ enum Wire {
     G,
 }
 
-impl From<char> for Wire {
-    fn from(input: char) -> Wire {
+impl TryFrom<char> for Wire {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Wire, ParseError> {
         match input {
-            'a' => Wire::A,
-            'b' => Wire::B,
-            'c' => Wire::C,
-            'd' => Wire::D,
-            'e' => Wire::E,
-            'f' => Wire::F,
-            'g' => Wire::G,
-            _ => panic!("Cannot convert '{}' to a wire!", input),
+            'a' => Ok(Wire::A),
+            'b' => Ok(Wire::B),
+            'c' => Ok(Wire::C),
+            'd' => Ok(Wire::D),
+            'e' => Ok(Wire::E),
+            'f' => Ok(Wire::F),
+            'g' => Ok(Wire::G),
+            _ => Err(ParseError::new(
+                &input.to_string(),
+                &format!("expected a wire from 'a' to 'g', but got '{}'", input),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Entry {
+    patterns: Vec<HashSet<Wire>>,
+    displays: Vec<HashSet<Wire>>,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Entry, ParseError> {
+        let (patterns_str, displays_str) = input
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::expected(input, ENTRY_FORMAT))?;
+
+        let patterns = get_wire_sets(patterns_str)?;
+        let displays = get_wire_sets(displays_str)?;
+
+        if patterns.len() != 10 || displays.len() != 4 {
+            return Err(ParseError::expected(input, ENTRY_FORMAT));
         }
+
+        Ok(Entry { patterns, displays })
     }
 }
 
@@ -42,6 +76,12 @@ pub struct Display {
     number_segments: HashMap<u8, HashSet<Segment>>,
 }
 
+impl Default for Display {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Display {
     pub fn new() -> Display {
         let mut number_segments = HashMap::new();
@@ -151,17 +191,19 @@ impl Display {
         }
     }
 
-    pub fn map_wires_to_segments(&mut self, unique_signal_patterns: &[String]) {
-        let wires_for_1 = Self::get_1_wires(unique_signal_patterns);
-        let wires_for_4 = Self::get_4_wires(unique_signal_patterns);
-        let wires_for_7 = Self::get_7_wires(unique_signal_patterns);
-        let wires_for_8 = Self::get_8_wires(unique_signal_patterns);
+    pub fn map_wires_to_segments(
+        &mut self,
+        unique_signal_patterns: &[HashSet<Wire>],
+    ) -> Result<(), String> {
+        let wires_for_1 = Self::get_wires_with_length(unique_signal_patterns, 2, 1)?;
+        let wires_for_4 = Self::get_wires_with_length(unique_signal_patterns, 4, 4)?;
+        let wires_for_7 = Self::get_wires_with_length(unique_signal_patterns, 3, 7)?;
+        let wires_for_8 = Self::get_wires_with_length(unique_signal_patterns, 7, 8)?;
 
         let segment_a_wire: Wire = *wires_for_7
             .iter()
-            .filter(|wire| !wires_for_1.contains(wire))
-            .nth(0)
-            .unwrap();
+            .find(|wire| !wires_for_1.contains(wire))
+            .ok_or_else(|| String::from("Patterns for 1 and 7 do not differ by one wire!"))?;
 
         self.wires_to_segments.insert(segment_a_wire, Segment::A);
 
@@ -181,117 +223,88 @@ impl Display {
             .collect();
 
         // only handle the 6 segment numbers (0, 6, 9)
-        for pattern_str in unique_signal_patterns {
-            if pattern_str.len() != 6 {
+        for pattern in unique_signal_patterns {
+            if pattern.len() != 6 {
                 continue;
             }
 
-            let pattern = get_unique_signal_pattern_wires(pattern_str);
-
             let remaining_wire = *wires_for_8
                 .iter()
-                .filter(|wire| !pattern.contains(wire))
-                .nth(0)
-                .unwrap();
+                .find(|wire| !pattern.contains(wire))
+                .ok_or_else(|| format!("Pattern {:?} is missing no wire!", pattern))?;
 
             if segment_c_and_f_wires.contains(&remaining_wire) {
-                let segment_f_wire: Wire = *segment_c_and_f_wires
-                    .iter()
-                    .filter(|&&wire| wire != remaining_wire)
-                    .nth(0)
-                    .unwrap();
+                let segment_f_wire = Self::get_other_wire(&segment_c_and_f_wires, remaining_wire)?;
 
                 self.wires_to_segments.insert(remaining_wire, Segment::C);
                 self.wires_to_segments.insert(segment_f_wire, Segment::F);
             } else if segment_b_and_d_wires.contains(&remaining_wire) {
-                let segment_b_wire: Wire = *segment_b_and_d_wires
-                    .iter()
-                    .filter(|&&wire| wire != remaining_wire)
-                    .nth(0)
-                    .unwrap();
+                let segment_b_wire = Self::get_other_wire(&segment_b_and_d_wires, remaining_wire)?;
 
                 self.wires_to_segments.insert(remaining_wire, Segment::D);
                 self.wires_to_segments.insert(segment_b_wire, Segment::B);
             } else if segment_e_and_g_wires.contains(&remaining_wire) {
-                let segment_g_wire: Wire = *segment_e_and_g_wires
-                    .iter()
-                    .filter(|&&wire| wire != remaining_wire)
-                    .nth(0)
-                    .unwrap();
+                let segment_g_wire = Self::get_other_wire(&segment_e_and_g_wires, remaining_wire)?;
 
                 self.wires_to_segments.insert(remaining_wire, Segment::E);
                 self.wires_to_segments.insert(segment_g_wire, Segment::G);
             }
         }
+
+        Ok(())
     }
 
-    pub fn get_display_number(&self, live_wire_pattern: &str) -> u8 {
-        let segments: HashSet<Segment> = live_wire_pattern
-            .chars()
-            .map(|c| Wire::from(c))
+    pub fn get_display_number(&self, live_wire_pattern: &HashSet<Wire>) -> Result<u8, String> {
+        let segments = live_wire_pattern
+            .iter()
             .map(|wire| {
                 self.wires_to_segments
-                    .get(&wire)
-                    .expect(&format!("Cannot get segment for wire: {:?}", wire))
-                    .clone()
+                    .get(wire)
+                    .copied()
+                    .ok_or_else(|| format!("Cannot get segment for wire: {:?}", wire))
             })
-            .collect();
-
-        for (number, number_segments) in &self.number_segments {
-            if segments == *number_segments {
-                return *number;
-            }
-        }
-
-        panic!("Could not find a number for segments: {:?}", segments);
-    }
+            .collect::<Result<HashSet<Segment>, String>>()?;
 
-    fn get_1_wires(unique_signal_patterns: &[String]) -> HashSet<Wire> {
-        get_unique_signal_pattern_wires(
-            unique_signal_patterns
-                .iter()
-                .find(|pattern| pattern.len() == 2)
-                .expect(&format!("Patterns missing 1!")),
-        )
+        self.number_segments
+            .iter()
+            .find(|(_, number_segments)| segments == **number_segments)
+            .map(|(number, _)| *number)
+            .ok_or_else(|| format!("Could not find a number for segments: {:?}", segments))
     }
 
-    fn get_4_wires(unique_signal_patterns: &[String]) -> HashSet<Wire> {
-        get_unique_signal_pattern_wires(
-            unique_signal_patterns
-                .iter()
-                .find(|pattern| pattern.len() == 4)
-                .expect(&format!("Patterns missing 4!")),
-        )
+    fn get_wires_with_length(
+        unique_signal_patterns: &[HashSet<Wire>],
+        length: usize,
+        number: u8,
+    ) -> Result<HashSet<Wire>, String> {
+        unique_signal_patterns
+            .iter()
+            .find(|pattern| pattern.len() == length)
+            .cloned()
+            .ok_or_else(|| format!("Patterns missing {}!", number))
     }
 
-    fn get_7_wires(unique_signal_patterns: &[String]) -> HashSet<Wire> {
-        get_unique_signal_pattern_wires(
-            unique_signal_patterns
-                .iter()
-                .find(|pattern| pattern.len() == 3)
-                .expect(&format!("Patterns missing 7!")),
-        )
+    fn get_other_wire(wires: &HashSet<Wire>, wire: Wire) -> Result<Wire, String> {
+        wires
+            .iter()
+            .copied()
+            .find(|&other_wire| other_wire != wire)
+            .ok_or_else(|| format!("No other wire than {:?} in {:?}!", wire, wires))
     }
+}
 
-    fn get_8_wires(unique_signal_patterns: &[String]) -> HashSet<Wire> {
-        get_unique_signal_pattern_wires(
-            unique_signal_patterns
-                .iter()
-                .find(|pattern| pattern.len() == 7)
-                .expect(&format!("Patterns missing 8!")),
-        )
-    }
+pub fn get_entries(input: &[String]) -> Result<Vec<Entry>, ParseError> {
+    parse_lines(input)
 }
 
-pub fn get_displays_for(inputs: &[String]) -> Vec<u32> {
-    inputs.iter().map(|input| get_display_for(input)).collect()
+pub fn get_displays_for(entries: &[Entry]) -> Result<Vec<u32>, String> {
+    entries.iter().map(get_display_for).collect()
 }
 
-pub fn get_number_of_1_4_7_or_8_displays(input: &[String]) -> u32 {
-    input
+pub fn get_number_of_1_4_7_or_8_displays(entries: &[Entry]) -> u32 {
+    entries
         .iter()
-        .map(|s| get_patterns_and_displays(s))
-        .flat_map(|(_, displays)| displays.into_iter())
+        .flat_map(|entry| entry.displays.iter())
         .fold(0, |acc, display| {
             acc + match display.len() {
                 2 | 3 | 4 | 7 => 1,
@@ -300,45 +313,33 @@ pub fn get_number_of_1_4_7_or_8_displays(input: &[String]) -> u32 {
         })
 }
 
-fn get_display_for(input: &str) -> u32 {
-    let (patterns, display_patterns) = get_patterns_and_displays(input);
-
+fn get_display_for(entry: &Entry) -> Result<u32, String> {
     let mut display = Display::new();
 
-    display.map_wires_to_segments(&patterns);
-
-    let mut result = String::new();
-
-    for display_pattern in display_patterns {
-        result += &display.get_display_number(&display_pattern).to_string();
-    }
+    display.map_wires_to_segments(&entry.patterns)?;
 
-    result
-        .parse()
-        .expect(&format!("Could not parse u32: {}", result))
+    entry.displays.iter().try_fold(0, |acc, display_pattern| {
+        Ok(acc * 10 + display.get_display_number(display_pattern)? as u32)
+    })
 }
 
-fn get_unique_signal_pattern_wires(unique_signal_pattern: &str) -> HashSet<Wire> {
-    unique_signal_pattern
-        .chars()
-        .map(|c| Wire::from(c))
-        .collect()
+fn get_unique_signal_pattern_wires(
+    unique_signal_pattern: &str,
+) -> Result<HashSet<Wire>, ParseError> {
+    unique_signal_pattern.chars().map(Wire::try_from).collect()
 }
 
-fn get_patterns_and_displays(input: &str) -> (Vec<String>, Vec<String>) {
-    let (patterns, displays) = input
-        .split_once(" | ")
-        .expect(&format!("Could not split ' | ' on: {}", input));
-
-    let patterns_vec = patterns.split(' ').map(|s| s.to_string()).collect();
-    let displays_vec = displays.split(' ').map(|s| s.to_string()).collect();
-
-    (patterns_vec, displays_vec)
+fn get_wire_sets(patterns: &str) -> Result<Vec<HashSet<Wire>>, ParseError> {
+    patterns
+        .split(' ')
+        .map(get_unique_signal_pattern_wires)
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_8::EXAMPLES;
 
     const TEST_INPUT: &str =
         "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
@@ -347,67 +348,42 @@ mod tests {
         "acedgfb", "cdfbe", "gcdfa", "fbcad", "dab", "cefabd", "cdfgeb", "eafb", "cagedb", "ab",
     ];
     const TEST_DISPLAY_PATTERNS: [&str; 4] = ["cdfeb", "fcadb", "cdfeb", "cdbaf"];
-    const TEST_DATA: [&str; 10] = [
-        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
-        "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
-        "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
-        "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
-        "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
-        "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
-        "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
-        "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
-        "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
-        "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
-    ];
-
     #[test]
-    fn test_display_get_1_wires() {
-        let input: Vec<String> = TEST_UNIQUE_SIGNAL_PATTERNS
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+    fn test_display_get_wires_with_length_1() {
+        let input = get_test_patterns();
 
         let expected = [Wire::A, Wire::B].into_iter().collect();
 
-        let result = Display::get_1_wires(&input);
+        let result = Display::get_wires_with_length(&input, 2, 1);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
-    fn test_display_get_4_wires() {
-        let input: Vec<String> = TEST_UNIQUE_SIGNAL_PATTERNS
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+    fn test_display_get_wires_with_length_4() {
+        let input = get_test_patterns();
 
         let expected = [Wire::E, Wire::A, Wire::F, Wire::B].into_iter().collect();
 
-        let result = Display::get_4_wires(&input);
+        let result = Display::get_wires_with_length(&input, 4, 4);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
-    fn test_display_get_7_wires() {
-        let input: Vec<String> = TEST_UNIQUE_SIGNAL_PATTERNS
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+    fn test_display_get_wires_with_length_7() {
+        let input = get_test_patterns();
 
         let expected = [Wire::D, Wire::A, Wire::B].into_iter().collect();
 
-        let result = Display::get_7_wires(&input);
+        let result = Display::get_wires_with_length(&input, 3, 7);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
-    fn test_display_get_8_wires() {
-        let input: Vec<String> = TEST_UNIQUE_SIGNAL_PATTERNS
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+    fn test_display_get_wires_with_length_8() {
+        let input = get_test_patterns();
 
         let expected = [
             Wire::A,
@@ -421,21 +397,18 @@ mod tests {
         .into_iter()
         .collect();
 
-        let result = Display::get_8_wires(&input);
+        let result = Display::get_wires_with_length(&input, 7, 8);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
     fn test_display_map_wires_to_segments() {
-        let input: Vec<String> = TEST_UNIQUE_SIGNAL_PATTERNS
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+        let input = get_test_patterns();
 
         let mut display = Display::new();
 
-        display.map_wires_to_segments(&input);
+        display.map_wires_to_segments(&input).unwrap();
 
         let mut expected = HashMap::new();
 
@@ -454,7 +427,7 @@ mod tests {
 
     #[test]
     fn test_display_get_display_number() {
-        let input = "cdfbe";
+        let input = get_unique_signal_pattern_wires("cdfbe").unwrap();
 
         let mut wires_to_segments = HashMap::new();
 
@@ -470,33 +443,41 @@ mod tests {
 
         display.wires_to_segments = wires_to_segments;
 
-        let expected = 5;
+        let expected = Ok(5);
 
-        let result = display.get_display_number(input);
+        let result = display.get_display_number(&input);
 
         assert_eq!(result, expected);
     }
 
     #[test]
     fn test_get_displays_for() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = get_test_entries();
 
         let expected = 61229;
 
-        let result = get_displays_for(&input).iter().fold(0, |acc, x| acc + x);
+        let result: u32 = get_displays_for(&input).unwrap().iter().sum();
 
         assert_eq!(result, expected);
     }
 
     #[test]
     fn test_get_display_for() {
-        let expected = 5353;
+        let expected = Ok(5353);
 
-        let result = get_display_for(TEST_INPUT);
+        let result = get_display_for(&TEST_INPUT.parse().unwrap());
 
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_get_display_for_error() {
+        let missing_one =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb abc | cdfeb fcadb cdfeb cdbaf";
+
+        assert!(get_display_for(&missing_one.parse().unwrap()).is_err());
+    }
+
     #[test]
     fn test_get_unique_signal_pattern_wires() {
         let input = "acedgfb";
@@ -513,12 +494,13 @@ mod tests {
 
         let result = get_unique_signal_pattern_wires(input);
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
+        assert!(get_unique_signal_pattern_wires("abh").is_err());
     }
 
     #[test]
     fn test_get_number_of_1_4_7_or_8_displays() {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = get_test_entries();
 
         let expected = 26;
 
@@ -528,20 +510,44 @@ mod tests {
     }
 
     #[test]
-    fn test_get_patterns_and_displays() {
-        let expected = (
-            TEST_UNIQUE_SIGNAL_PATTERNS
+    fn test_entry_from_str() {
+        let expected = Entry {
+            patterns: get_test_patterns(),
+            displays: TEST_DISPLAY_PATTERNS
                 .iter()
-                .map(|s| s.to_string())
+                .map(|pattern| get_unique_signal_pattern_wires(pattern).unwrap())
                 .collect(),
-            TEST_DISPLAY_PATTERNS
-                .iter()
-                .map(|s| s.to_string())
-                .collect(),
-        );
+        };
 
-        let result = get_patterns_and_displays(TEST_INPUT);
+        let result = TEST_INPUT.parse();
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_get_entries_error() {
+        let input: Vec<String> = [TEST_INPUT, "be cfbegad | fdgacbe cefdb cefbgd gcbe"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let expected = "2: expected '10 patterns | 4 digits'";
+
+        let result = get_entries(&input).unwrap_err().to_string();
 
         assert_eq!(result, expected);
     }
+
+    fn get_test_patterns() -> Vec<HashSet<Wire>> {
+        TEST_UNIQUE_SIGNAL_PATTERNS
+            .iter()
+            .map(|pattern| get_unique_signal_pattern_wires(pattern).unwrap())
+            .collect()
+    }
+
+    fn get_test_entries() -> Vec<Entry> {
+        let input = EXAMPLES[0].get_lines();
+
+        get_entries(&input).unwrap()
+    }
 }
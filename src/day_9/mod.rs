@@ -1,22 +1,49 @@
 mod smoke_basin;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::{Answer, Example, Solution};
+use crate::util::parse_error::ParseError;
+pub use smoke_basin::CaveFloor;
 
-use smoke_basin::CaveFloor;
+const EXAMPLES: [Example; 1] = [Example {
+    input: "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678",
+    part_one: Some("15"),
+    part_two: Some("1134"),
+}];
 
-pub fn run_day_9() {
-    let file_input = to_string_vector("inputs/day_9.txt").unwrap();
+pub struct Day9;
 
-    let mut cave_floor = CaveFloor::new();
+impl Solution for Day9 {
+    type Input = CaveFloor;
 
-    cave_floor.set_height_map(&file_input);
+    fn parse(&self, input: &[String]) -> Result<CaveFloor, ParseError> {
+        let mut cave_floor = CaveFloor::new();
 
-    let part_1_result: u32 = cave_floor.get_risk_levels().iter().sum();
-    let part_2_result: usize = cave_floor
-        .get_three_largest_basins()
-        .iter()
-        .fold(1, |acc, basin| acc * basin.len());
+        cave_floor.set_height_map(input)?;
 
-    println!("Day 9 Part 1: {}", part_1_result);
-    println!("Day 9 Part 2: {}", part_2_result);
+        Ok(cave_floor)
+    }
+
+    fn part_one(&self, cave_floor: &CaveFloor) -> Result<Answer, String> {
+        let result: u32 = cave_floor.get_risk_levels().iter().sum();
+
+        Ok(result.into())
+    }
+
+    fn part_two(&self, cave_floor: &CaveFloor) -> Result<Answer, String> {
+        let result: usize = cave_floor
+            .get_three_largest_basins()
+            .iter()
+            .fold(1, |acc, basin| acc * basin.len());
+
+        Ok(result.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &EXAMPLES
+    }
 }
@@ -1,28 +1,40 @@
-use std::collections::{HashSet, VecDeque};
+use crate::util::grid::Grid;
+use crate::util::parse_error::ParseError;
+use crate::util::point_2d::Point2d;
+use crate::util::search::find_reachable;
+use std::collections::HashSet;
 
 pub struct CaveFloor {
-    height_map: Vec<Vec<u32>>,
+    height_map: Grid<u32>,
+}
+
+impl Default for CaveFloor {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CaveFloor {
     pub fn new() -> CaveFloor {
         CaveFloor {
-            height_map: Vec::new(),
+            height_map: Grid::default(),
         }
     }
 
-    pub fn set_height_map(&mut self, input: &[String]) {
-        self.height_map = input.iter().map(|line| Self::string_to_vec(line)).collect();
+    pub fn set_height_map(&mut self, input: &[String]) -> Result<(), ParseError> {
+        self.height_map = Grid::from_digit_lines(input)?;
+
+        Ok(())
     }
 
     pub fn get_risk_levels(&self) -> Vec<u32> {
         self.get_low_points()
             .iter()
-            .map(|&(column, row)| self.get_height_at(column, row) + 1)
+            .map(|point| self.get_height_at(point) + 1)
             .collect()
     }
 
-    pub fn get_three_largest_basins(&self) -> Vec<HashSet<(usize, usize)>> {
+    pub fn get_three_largest_basins(&self) -> Vec<HashSet<Point2d<i32>>> {
         let mut basins = self.get_basins();
 
         basins.sort_by(|basin_1, basin_2| basin_1.len().cmp(&basin_2.len()));
@@ -32,163 +44,52 @@ impl CaveFloor {
         basins.into_iter().take(3).collect()
     }
 
-    fn get_basins(&self) -> Vec<HashSet<(usize, usize)>> {
+    fn get_basins(&self) -> Vec<HashSet<Point2d<i32>>> {
         self.get_low_points()
             .iter()
-            .map(|&(column, row)| self.get_basin_for_low_point(column, row))
+            .map(|low_point| self.get_basin_for_low_point(low_point))
             .collect()
     }
 
-    fn get_basin_for_low_point(
-        &self,
-        low_point_column: usize,
-        low_point_row: usize,
-    ) -> HashSet<(usize, usize)> {
-        let mut seen_locations = HashSet::new();
-        let mut points_to_check = VecDeque::from([(low_point_column, low_point_row)]);
-
-        while !points_to_check.is_empty() {
-            let (column, row) = points_to_check.pop_front().expect("Queue was empty!");
-
-            if let Some(height) = self.get_height_above(column, row) {
-                if height != 9 && !seen_locations.contains(&(column, row - 1)) {
-                    points_to_check.push_back((column, row - 1));
-                }
-            }
-
-            if let Some(height) = self.get_height_below(column, row) {
-                if height != 9 && !seen_locations.contains(&(column, row + 1)) {
-                    points_to_check.push_back((column, row + 1));
-                }
-            }
-
-            if let Some(height) = self.get_height_left_of(column, row) {
-                if height != 9 && !seen_locations.contains(&(column - 1, row)) {
-                    points_to_check.push_back((column - 1, row));
-                }
-            }
-
-            if let Some(height) = self.get_height_right_of(column, row) {
-                if height != 9 && !seen_locations.contains(&(column + 1, row)) {
-                    points_to_check.push_back((column + 1, row));
-                }
-            }
-
-            seen_locations.insert((column, row));
-        }
-
-        seen_locations
+    fn get_basin_for_low_point(&self, low_point: &Point2d<i32>) -> HashSet<Point2d<i32>> {
+        find_reachable(*low_point, |point| {
+            self.height_map
+                .get_neighbors(point)
+                .filter(|neighbor| self.get_height_at(neighbor) != 9)
+                .collect::<Vec<Point2d<i32>>>()
+        })
+        .into_iter()
+        .collect()
     }
 
-    fn get_low_points(&self) -> HashSet<(usize, usize)> {
-        let row_size = self.height_map.len();
-        let column_size = self.height_map.get(0).expect("Height map is empty!").len();
-
-        let mut result = HashSet::new();
-
-        for row_index in 0..row_size {
-            for column_index in 0..column_size {
-                if self.is_low_point(column_index, row_index) {
-                    result.insert((column_index, row_index));
-                }
-            }
-        }
-
-        result
+    fn get_low_points(&self) -> HashSet<Point2d<i32>> {
+        self.height_map
+            .points()
+            .filter(|point| self.is_low_point(point))
+            .collect()
     }
 
-    fn is_low_point(&self, column: usize, row: usize) -> bool {
-        let height_at_point = self.get_height_at(column, row);
-
-        let mut result = true;
+    fn is_low_point(&self, point: &Point2d<i32>) -> bool {
+        let height_at_point = self.get_height_at(point);
 
         if height_at_point == 9 {
             return false;
         }
 
-        if let Some(height) = self.get_height_above(column, row) {
-            result &= height_at_point < height;
-        }
-
-        if let Some(height) = self.get_height_below(column, row) {
-            result &= height_at_point < height;
-        }
-
-        if let Some(height) = self.get_height_left_of(column, row) {
-            result &= height_at_point < height;
-        }
-
-        if let Some(height) = self.get_height_right_of(column, row) {
-            result &= height_at_point < height;
-        }
-
-        result
-    }
-
-    fn get_height_above(&self, column: usize, row: usize) -> Option<u32> {
-        if row != 0 {
-            Some(self.get_height_at(column, row - 1))
-        } else {
-            None
-        }
-    }
-
-    fn get_height_below(&self, column: usize, row: usize) -> Option<u32> {
-        let row_size = self.height_map.len();
-
-        if row != (row_size - 1) {
-            Some(self.get_height_at(column, row + 1))
-        } else {
-            None
-        }
+        self.height_map
+            .get_neighbors(point)
+            .all(|neighbor| height_at_point < self.get_height_at(&neighbor))
     }
 
-    fn get_height_left_of(&self, column: usize, row: usize) -> Option<u32> {
-        if column != 0 {
-            Some(self.get_height_at(column - 1, row))
-        } else {
-            None
-        }
-    }
-
-    fn get_height_right_of(&self, column: usize, row: usize) -> Option<u32> {
-        let column_size = self.height_map.get(0).expect("Height map is empty!").len();
-
-        if column != (column_size - 1) {
-            Some(self.get_height_at(column + 1, row))
-        } else {
-            None
-        }
-    }
-
-    fn get_height_at(&self, column: usize, row: usize) -> u32 {
-        *self
-            .height_map
-            .get(row)
-            .expect(&format!("Could not get row: {}", row))
-            .get(column)
-            .expect(&format!("Could not get column: {}", column))
-    }
-
-    fn string_to_vec(input: &str) -> Vec<u32> {
-        input
-            .chars()
-            .map(|c| c.to_digit(10).expect(&format!("Could not parse: {}", c)))
-            .collect()
+    fn get_height_at(&self, point: &Point2d<i32>) -> u32 {
+        self.height_map.get(point).copied().unwrap_or(9)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: [&str; 5] = [
-        "2199943210",
-        "3987894921",
-        "9856789892",
-        "8767896789",
-        "9899965678",
-    ];
+    use crate::day_9::EXAMPLES;
 
     #[test]
     fn test_cave_floor_get_risk_levels() {
@@ -212,12 +113,12 @@ mod tests {
         let expected_center_1 = false;
         let expected_center_2 = true;
 
-        let result_corner_1 = cave_floor.is_low_point(0, 0);
-        let result_corner_2 = cave_floor.is_low_point(9, 0);
-        let result_edge_1 = cave_floor.is_low_point(0, 2);
-        let result_edge_2 = cave_floor.is_low_point(6, 4);
-        let result_center_1 = cave_floor.is_low_point(1, 1);
-        let result_center_2 = cave_floor.is_low_point(2, 2);
+        let result_corner_1 = cave_floor.is_low_point(&Point2d::new(0, 0));
+        let result_corner_2 = cave_floor.is_low_point(&Point2d::new(9, 0));
+        let result_edge_1 = cave_floor.is_low_point(&Point2d::new(0, 2));
+        let result_edge_2 = cave_floor.is_low_point(&Point2d::new(6, 4));
+        let result_center_1 = cave_floor.is_low_point(&Point2d::new(1, 1));
+        let result_center_2 = cave_floor.is_low_point(&Point2d::new(2, 2));
 
         assert_eq!(result_corner_1, expected_corner_1);
         assert_eq!(result_corner_2, expected_corner_2);
@@ -243,9 +144,10 @@ mod tests {
             (9, 2),
         ]
         .into_iter()
+        .map(|(x, y)| Point2d::new(x, y))
         .collect();
 
-        let result = cave_floor.get_basin_for_low_point(9, 0);
+        let result = cave_floor.get_basin_for_low_point(&Point2d::new(9, 0));
 
         assert_eq!(result, expected);
     }
@@ -265,11 +167,11 @@ mod tests {
     }
 
     fn get_test_cave_floor() -> CaveFloor {
-        let input: Vec<String> = TEST_DATA.iter().map(|s| s.to_string()).collect();
+        let input = EXAMPLES[0].get_lines();
 
         let mut cave_floor = CaveFloor::new();
 
-        cave_floor.set_height_map(&input);
+        cave_floor.set_height_map(&input).unwrap();
 
         cave_floor
     }
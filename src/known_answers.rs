@@ -0,0 +1,232 @@
+use crate::solution::{Answer, Part};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CheckStatus {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckStatus::Pass => f.pad("PASS"),
+            CheckStatus::Fail { .. } => f.pad("FAIL"),
+            CheckStatus::Missing => f.pad("MISSING"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u32, Part), String>,
+}
+
+impl KnownAnswers {
+    pub fn new() -> KnownAnswers {
+        KnownAnswers {
+            answers: BTreeMap::new(),
+        }
+    }
+
+    pub fn load(file_name: &str) -> Result<KnownAnswers, String> {
+        if !Path::new(file_name).exists() {
+            return Ok(KnownAnswers::new());
+        }
+
+        fs::read_to_string(file_name)
+            .map_err(|error| format!("Could not read {}: {}", file_name, error))?
+            .parse()
+            .map_err(|error| format!("{}:{}", file_name, error))
+    }
+
+    pub fn save(&self, file_name: &str) -> Result<(), String> {
+        fs::write(file_name, self.to_string())
+            .map_err(|error| format!("Could not write {}: {}", file_name, error))
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, answer: &Answer) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    pub fn check(&self, day: u32, part: Part, actual: Result<&Answer, &str>) -> CheckStatus {
+        check_answer(self.get(day, part), actual)
+    }
+}
+
+impl FromStr for KnownAnswers {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<KnownAnswers, String> {
+        let mut known_answers = KnownAnswers::new();
+
+        for (line_index, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (day, part, answer) = parse_answer_line(line)
+                .ok_or_else(|| format!("{}: expected 'day part answer'", line_index + 1))?;
+
+            known_answers.answers.insert((day, part), answer);
+        }
+
+        Ok(known_answers)
+    }
+}
+
+impl fmt::Display for KnownAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# day part answer")?;
+
+        for ((day, part), answer) in self.answers.iter() {
+            writeln!(f, "{} {} {}", day, part, escape(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn check_answer(expected: Option<&str>, actual: Result<&Answer, &str>) -> CheckStatus {
+    let expected = match expected {
+        Some(expected) => expected,
+        None => return CheckStatus::Missing,
+    };
+
+    match actual {
+        Ok(answer) if answer.to_string() == expected => CheckStatus::Pass,
+        Ok(answer) => CheckStatus::Fail {
+            expected: expected.to_string(),
+            actual: answer.to_string(),
+        },
+        Err(error) => CheckStatus::Fail {
+            expected: expected.to_string(),
+            actual: error.to_string(),
+        },
+    }
+}
+
+fn parse_answer_line(line: &str) -> Option<(u32, Part, String)> {
+    let mut split = line.splitn(3, ' ');
+
+    let day = split.next()?.parse().ok()?;
+
+    let part = match split.next()? {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+
+    let answer = unescape(split.next()?)?;
+
+    Some((day, part, answer))
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut characters = answer.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            result.push(character);
+
+            continue;
+        }
+
+        match characters.next()? {
+            'n' => result.push('\n'),
+            '\\' => result.push('\\'),
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &str = "# day part answer\n1 1 1553\n1 2 1597\n13 2 #  #\\n ##\\\\\n";
+
+    #[test]
+    fn test_from_str() {
+        let known_answers: KnownAnswers = TEST_DATA.parse().unwrap();
+
+        let expected = vec![Some("1553"), Some("1597"), None, Some("#  #\n ##\\")];
+
+        let result = vec![
+            known_answers.get(1, Part::One),
+            known_answers.get(1, Part::Two),
+            known_answers.get(13, Part::One),
+            known_answers.get(13, Part::Two),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        let expected = Err(String::from("2: expected 'day part answer'"));
+
+        let result = "1 1 1553\n1 3 1597".parse::<KnownAnswers>();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let known_answers: KnownAnswers = TEST_DATA.parse().unwrap();
+
+        let result = known_answers.to_string();
+
+        assert_eq!(result, TEST_DATA);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut known_answers = KnownAnswers::new();
+
+        known_answers.insert(1, Part::One, &Answer::from(1553));
+        known_answers.insert(1, Part::Two, &Answer::from(1597));
+
+        let expected = vec![
+            CheckStatus::Pass,
+            CheckStatus::Fail {
+                expected: String::from("1597"),
+                actual: String::from("1598"),
+            },
+            CheckStatus::Fail {
+                expected: String::from("1597"),
+                actual: String::from("oops"),
+            },
+            CheckStatus::Missing,
+        ];
+
+        let result = vec![
+            known_answers.check(1, Part::One, Ok(&Answer::from(1553))),
+            known_answers.check(1, Part::Two, Ok(&Answer::from(1598))),
+            known_answers.check(1, Part::Two, Err("oops")),
+            known_answers.check(2, Part::One, Ok(&Answer::from(7))),
+        ];
+
+        assert_eq!(result, expected);
+    }
+}
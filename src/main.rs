@@ -1,81 +1,508 @@
 #[macro_use]
 extern crate clap;
 
-pub mod util;
-
-
-mod day_1;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_2;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
-
-use clap::App;
+use adventofcode2021::benchmark::benchmark_day;
+use adventofcode2021::known_answers::{
+    check_answer, CheckStatus, KnownAnswers, DEFAULT_ANSWERS_FILE,
+};
+use adventofcode2021::report::{to_csv, to_json, OutputFormat, PartRecord};
+use adventofcode2021::runner::{
+    get_input, get_input_file_name, get_solver, run_days, run_examples, run_guarded, DayResult,
+    PartResult, NUMBER_OF_DAYS,
+};
+use adventofcode2021::solution::{Answer, Part};
+use clap::{App, ArgMatches};
+use std::panic;
+use std::process::{self, Command};
+use std::time::Duration;
 
 fn print_seperator() {
     println!("-------------------------------");
 }
 
-fn run_day(day: u32) {
-    match day {
-        1 => day_1::run_day_1(),
-        2 => day_2::run_day_2(),
-        3 => day_3::run_day_3(),
-        4 => day_4::run_day_4(),
-        5 => day_5::run_day_5(),
-        6 => day_6::run_day_6(),
-        7 => day_7::run_day_7(),
-        8 => day_8::run_day_8(),
-        9 => day_9::run_day_9(),
-        10 => day_10::run_day_10(),
-        11 => day_11::run_day_11(),
-        12 => day_12::run_day_12(),
-        13 => day_13::run_day_13(),
-        14 => day_14::run_day_14(),
-        15 => day_15::run_day_15(),
-        16 => day_16::run_day_16(),
-        17 => day_17::run_day_17(),
-        18 => day_18::run_day_18(),
-        19 => day_19::run_day_19(),
-        20 => day_20::run_day_20(),
-        21 => day_21::run_day_21(),
-        22 => day_22::run_day_22(),
-        23 => day_23::run_day_23(),
-        24 => day_24::run_day_24(),
-        25 => day_25::run_day_25(),
-        _ => panic!("Can't run this day!!!!!!"),
+fn get_parts(part: &str) -> Vec<Part> {
+    match part {
+        "1" => vec![Part::One],
+        "2" => vec![Part::Two],
+        _ => vec![Part::One, Part::Two],
     }
 }
 
+fn print_answer(day: u32, part: Part, answer: &Answer) {
+    if answer.is_multi_line() {
+        println!("Day {} Part {}:\n{}", day, part, answer);
+    } else {
+        println!("Day {} Part {}: {}", day, part, answer);
+    }
+}
+
+fn run_day(day: u32, parts: &[Part], file_name: &str, show_diagram: bool) {
+    let solver = get_solver(day).unwrap_or_else(|| {
+        eprintln!("Can't run day {}!!!!!!", day);
+        process::exit(2);
+    });
+
+    let input = get_input(file_name).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+
+    panic::set_hook(Box::new(|_| {}));
+
+    let (parsed_input, _) = run_guarded(|| {
+        solver
+            .parse_input(&input)
+            .map_err(|error| error.in_file(file_name))
+    })
+    .unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+
+    for &part in parts {
+        let (answer, _) =
+            run_guarded(|| solver.solve(&parsed_input, part)).unwrap_or_else(|error| {
+                eprintln!("Day {} Part {}: {}", day, part, error);
+                process::exit(1);
+            });
+
+        print_answer(day, part, &answer);
+
+        if !show_diagram {
+            continue;
+        }
+
+        let diagram = run_guarded(|| solver.get_diagram(&parsed_input, part).transpose());
+
+        match diagram {
+            Ok((Some(diagram), _)) => println!("{}", diagram),
+            Ok((None, _)) => println!("Day {} has no diagram", day),
+            Err(error) => {
+                eprintln!("Day {} Part {}: {}", day, part, error);
+                process::exit(1);
+            }
+        }
+    }
+
+    let _ = panic::take_hook();
+}
+
+fn run_every_day(days: &[(u32, String)], parts: &[Part], jobs: usize) {
+    panic::set_hook(Box::new(|_| {}));
+
+    let day_results = run_days(days, parts, jobs);
+
+    let _ = panic::take_hook();
+
+    let mut multi_line_answers = Vec::new();
+
+    println!(
+        "{:>3}  {:>4}  {:<24}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+    print_seperator();
+
+    let mut total_time = Duration::ZERO;
+
+    for DayResult {
+        day,
+        parts: results,
+        parse_time,
+    } in day_results
+    {
+        total_time += parse_time;
+
+        for (part, result) in results.iter() {
+            let (answer, time) = match result {
+                PartResult::Solved(answer, elapsed) if answer.is_multi_line() => {
+                    multi_line_answers.push((day, *part, answer.clone()));
+
+                    (String::from("(see below)"), format!("{:.3?}", elapsed))
+                }
+                PartResult::Solved(answer, elapsed) => {
+                    (answer.to_string(), format!("{:.3?}", elapsed))
+                }
+                PartResult::NotImplemented => (String::from("not implemented"), String::from("-")),
+                PartResult::Failed(message) => (format!("error: {}", message), String::from("-")),
+            };
+
+            if let PartResult::Solved(_, elapsed) = result {
+                total_time += *elapsed;
+            }
+
+            println!("{:>3}  {:>4}  {:<24}  {:>12}", day, part, answer, time);
+        }
+    }
+
+    print_seperator();
+    println!("Total time: {:.3?}", total_time);
+
+    for (day, part, answer) in multi_line_answers {
+        print_seperator();
+        print_answer(day, part, &answer);
+    }
+}
+
+fn get_days(matches: &ArgMatches) -> Vec<(u32, String)> {
+    if matches.is_present("all") {
+        return (1..=(NUMBER_OF_DAYS as u32))
+            .map(|day| (day, get_input_file_name(day)))
+            .collect();
+    }
+
+    let day = value_t!(matches.value_of("day"), u32).unwrap_or_else(|error| error.exit());
+
+    if !(1..=(NUMBER_OF_DAYS as u32)).contains(&day) {
+        eprintln!("Day must be between 1 and {}, got {}", NUMBER_OF_DAYS, day);
+        process::exit(2);
+    }
+
+    let file_name = matches
+        .value_of("input")
+        .map_or_else(|| get_input_file_name(day), String::from);
+
+    vec![(day, file_name)]
+}
+
+fn print_records<F>(days: &[(u32, String)], parts: &[Part], jobs: usize, to_output: F)
+where
+    F: Fn(&[PartRecord]) -> String,
+{
+    panic::set_hook(Box::new(|_| {}));
+
+    let day_results = run_days(days, parts, jobs);
+
+    let _ = panic::take_hook();
+
+    let records: Vec<PartRecord> = day_results
+        .iter()
+        .flat_map(|day_result| {
+            day_result
+                .parts
+                .iter()
+                .map(|(part, result)| PartRecord::new(day_result.day, *part, result))
+                .collect::<Vec<PartRecord>>()
+        })
+        .collect();
+
+    println!("{}", to_output(&records));
+}
+
+fn check_days(
+    days: &[(u32, String)],
+    parts: &[Part],
+    jobs: usize,
+    answers_file_name: &str,
+    record: bool,
+) -> bool {
+    let mut known_answers = KnownAnswers::load(answers_file_name).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+
+    panic::set_hook(Box::new(|_| {}));
+
+    let day_results = run_days(days, parts, jobs);
+
+    let _ = panic::take_hook();
+
+    let mut number_of_failures = 0;
+    let mut number_recorded = 0;
+
+    for DayResult {
+        day,
+        parts: results,
+        ..
+    } in day_results
+    {
+        for (part, result) in results.iter() {
+            let actual = match result {
+                PartResult::Solved(answer, _) => Ok(answer),
+                PartResult::NotImplemented => Err("not implemented"),
+                PartResult::Failed(message) => Err(message.as_str()),
+            };
+
+            let status = known_answers.check(day, *part, actual);
+
+            match (&status, actual) {
+                (CheckStatus::Fail { expected, actual }, _) => {
+                    number_of_failures += 1;
+
+                    println!(
+                        "Day {:>2} Part {}: {} (expected {:?}, got {:?})",
+                        day, part, status, expected, actual
+                    );
+                }
+                (CheckStatus::Missing, Ok(answer)) if record => {
+                    number_recorded += 1;
+
+                    known_answers.insert(day, *part, answer);
+
+                    println!("Day {:>2} Part {}: RECORDED", day, part);
+                }
+                _ => println!("Day {:>2} Part {}: {}", day, part, status),
+            }
+        }
+    }
+
+    if number_recorded > 0 {
+        known_answers
+            .save(answers_file_name)
+            .unwrap_or_else(|error| {
+                eprintln!("{}", error);
+                process::exit(2);
+            });
+
+        print_seperator();
+        println!(
+            "Recorded {} answer(s) in {}",
+            number_recorded, answers_file_name
+        );
+    }
+
+    number_of_failures == 0
+}
+
+fn check_examples(days: &[(u32, String)], parts: &[Part]) -> bool {
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut number_of_failures = 0;
+
+    for (day, _) in days {
+        let example_results = run_examples(*day, parts);
+
+        if example_results.is_empty() {
+            println!("Day {:>2}: no examples", day);
+        }
+
+        for (index, (example, results)) in example_results.iter().enumerate() {
+            for (part, result) in results.iter() {
+                let expected = match example.get_answer(*part) {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+
+                let actual = match result {
+                    PartResult::Solved(answer, _) => Ok(answer),
+                    PartResult::NotImplemented => Err("not implemented"),
+                    PartResult::Failed(message) => Err(message.as_str()),
+                };
+
+                let status = check_answer(Some(expected), actual);
+
+                if let CheckStatus::Fail { expected, actual } = &status {
+                    number_of_failures += 1;
+
+                    println!(
+                        "Day {:>2} Example {} Part {}: {} (expected {:?}, got {:?})",
+                        day,
+                        index + 1,
+                        part,
+                        status,
+                        expected,
+                        actual
+                    );
+                } else {
+                    println!(
+                        "Day {:>2} Example {} Part {}: {}",
+                        day,
+                        index + 1,
+                        part,
+                        status
+                    );
+                }
+            }
+        }
+    }
+
+    let _ = panic::take_hook();
+
+    number_of_failures == 0
+}
+
+fn get_revision() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+fn benchmark_days(days: &[(u32, String)], parts: &[Part], runs: usize, history: Option<&str>) {
+    let revision = get_revision();
+
+    panic::set_hook(Box::new(|_| {}));
+
+    for (day, file_name) in days {
+        let report = get_solver(*day)
+            .ok_or_else(|| format!("Can't run day {}!!!!!!", day))
+            .and_then(|solver| {
+                let input = get_input(file_name)?;
+
+                benchmark_day(*day, solver, &input, parts, runs)
+            });
+
+        print_seperator();
+
+        match report {
+            Ok(report) => {
+                print!("{}", report);
+
+                if let Some(history) = history {
+                    report
+                        .append_to_history(history, &revision)
+                        .unwrap_or_else(|error| eprintln!("{}", error));
+                }
+            }
+            Err(error) => println!("Day {}: error: {}", day, error),
+        }
+    }
+
+    let _ = panic::take_hook();
+}
+
 fn main() {
     let cli_yaml = load_yaml!("cli.yml");
     let matches = App::from_yaml(cli_yaml).get_matches();
 
-    let day: u32 = value_t!(matches.value_of("day"), u32).unwrap();
+    let parts = get_parts(matches.value_of("part").unwrap_or("both"));
+
+    let days = get_days(&matches);
+
+    let jobs = if matches.is_present("jobs") {
+        value_t!(matches.value_of("jobs"), usize).unwrap_or_else(|error| error.exit())
+    } else {
+        1
+    };
+
+    if matches.is_present("bench") {
+        let runs = value_t!(matches.value_of("bench"), usize).unwrap_or_else(|error| error.exit());
+
+        benchmark_days(&days, &parts, runs, matches.value_of("history"));
+
+        return;
+    }
+
+    if matches.is_present("example") {
+        print_seperator();
+        println!("Running Examples");
+        print_seperator();
+
+        if !check_examples(&days, &parts) {
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    if matches.is_present("check") || matches.is_present("record") {
+        let answers_file_name = matches.value_of("answers").unwrap_or(DEFAULT_ANSWERS_FILE);
+
+        print_seperator();
+        println!("Checking against: {}", answers_file_name);
+        print_seperator();
+
+        if !check_days(
+            &days,
+            &parts,
+            jobs,
+            answers_file_name,
+            matches.is_present("record"),
+        ) {
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    let format = if matches.is_present("format") {
+        value_t!(matches.value_of("format"), OutputFormat).unwrap_or_else(|error| error.exit())
+    } else {
+        OutputFormat::Text
+    };
+
+    match format {
+        OutputFormat::Json => return print_records(&days, &parts, jobs, to_json),
+        OutputFormat::Csv => return print_records(&days, &parts, jobs, to_csv),
+        OutputFormat::Text => (),
+    }
+
+    if matches.is_present("all") {
+        print_seperator();
+        println!("Running All Days");
+        print_seperator();
+
+        run_every_day(&days, &parts, jobs);
+
+        return;
+    }
+
+    let (day, file_name) = &days[0];
 
     print_seperator();
     println!("Running Day: {}", day);
     print_seperator();
-    
-    run_day(day);
+
+    run_day(*day, &parts, file_name, matches.is_present("diagram"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_without_jobs() {
+        let cli_yaml = load_yaml!("cli.yml");
+
+        let matches = App::from_yaml(cli_yaml)
+            .get_matches_from_safe(["adventofcode2021", "--day", "1", "--bench", "3"])
+            .unwrap();
+
+        assert_eq!(matches.value_of("bench"), Some("3"));
+        assert!(!matches.is_present("jobs"));
+    }
+
+    #[test]
+    fn test_bench_with_jobs() {
+        let cli_yaml = load_yaml!("cli.yml");
+
+        let result = App::from_yaml(cli_yaml).get_matches_from_safe([
+            "adventofcode2021",
+            "--day",
+            "1",
+            "--bench",
+            "3",
+            "--jobs",
+            "2",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_format_conflicts() {
+        let expected = vec![true, true, true, true, false, false];
+
+        let result: Vec<bool> = [
+            vec!["--format", "json", "--check"],
+            vec!["--format", "json", "--example"],
+            vec!["--format", "csv", "--bench", "3"],
+            vec!["--format", "text", "--diagram"],
+            vec!["--check"],
+            vec!["--diagram"],
+        ]
+        .iter()
+        .map(|args| {
+            let cli_yaml = load_yaml!("cli.yml");
+
+            let args = ["adventofcode2021", "--day", "1"].iter().chain(args.iter());
+
+            App::from_yaml(cli_yaml).get_matches_from_safe(args).is_err()
+        })
+        .collect();
+
+        assert_eq!(result, expected);
+    }
 }
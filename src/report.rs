@@ -0,0 +1,231 @@
+use crate::runner::PartResult;
+use crate::solution::Part;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<OutputFormat, String> {
+        match input {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("Unknown output format: {}", input)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => f.pad("ok"),
+            Status::Unimplemented => f.pad("unimplemented"),
+            Status::Error => f.pad("error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PartRecord {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub time: Option<Duration>,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    pub fn new(day: u32, part: Part, result: &PartResult) -> PartRecord {
+        let (answer, time, status, error) = match result {
+            PartResult::Solved(answer, elapsed) => {
+                (Some(answer.to_string()), Some(*elapsed), Status::Ok, None)
+            }
+            PartResult::NotImplemented => (None, None, Status::Unimplemented, None),
+            PartResult::Failed(message) => (None, None, Status::Error, Some(message.clone())),
+        };
+
+        PartRecord {
+            day,
+            part,
+            answer,
+            time,
+            status,
+            error,
+        }
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"time_ns\":{},\"status\":\"{}\",\"error\":{}}}",
+            self.day,
+            self.part,
+            self.answer
+                .as_deref()
+                .map_or_else(|| String::from("null"), json_string),
+            self.time
+                .map_or_else(|| String::from("null"), |time| time.as_nanos().to_string()),
+            self.status,
+            self.error
+                .as_deref()
+                .map_or_else(|| String::from("null"), json_string)
+        )
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.answer.as_deref().map_or_else(String::new, csv_field),
+            self.time
+                .map_or_else(String::new, |time| time.as_nanos().to_string()),
+            self.status,
+            self.error.as_deref().map_or_else(String::new, csv_field)
+        )
+    }
+}
+
+pub fn to_json(records: &[PartRecord]) -> String {
+    let records: Vec<String> = records
+        .iter()
+        .map(|record| format!("  {}", record.to_json()))
+        .collect();
+
+    if records.is_empty() {
+        String::from("[]")
+    } else {
+        format!("[\n{}\n]", records.join(",\n"))
+    }
+}
+
+pub fn to_csv(records: &[PartRecord]) -> String {
+    let mut lines = vec![String::from("day,part,answer,time_ns,status,error")];
+
+    lines.extend(records.iter().map(|record| record.to_csv()));
+
+    lines.join("\n")
+}
+
+fn json_string(input: &str) -> String {
+    let mut result = String::from("\"");
+
+    for character in input.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            character if character.is_control() => {
+                result.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => result.push(character),
+        }
+    }
+
+    result.push('"');
+
+    result
+}
+
+fn csv_field(input: &str) -> String {
+    if input.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", input.replace('"', "\"\""))
+    } else {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+
+    fn get_test_records() -> Vec<PartRecord> {
+        vec![
+            PartRecord::new(
+                1,
+                Part::One,
+                &PartResult::Solved(Answer::from(1553), Duration::from_nanos(70384)),
+            ),
+            PartRecord::new(
+                13,
+                Part::Two,
+                &PartResult::Solved(Answer::from("# \"a\"\n,#"), Duration::from_nanos(12)),
+            ),
+            PartRecord::new(17, Part::One, &PartResult::NotImplemented),
+            PartRecord::new(
+                18,
+                Part::Two,
+                &PartResult::Failed(String::from("could not read inputs/day_18.txt")),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_output_format_from_str() {
+        let expected = vec![
+            Ok(OutputFormat::Text),
+            Ok(OutputFormat::Json),
+            Ok(OutputFormat::Csv),
+            Err(String::from("Unknown output format: xml")),
+        ];
+
+        let result: Vec<Result<OutputFormat, String>> = ["text", "json", "csv", "xml"]
+            .iter()
+            .map(|input| input.parse())
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let expected = [
+            "[",
+            "  {\"day\":1,\"part\":1,\"answer\":\"1553\",\"time_ns\":70384,\"status\":\"ok\",\"error\":null},",
+            "  {\"day\":13,\"part\":2,\"answer\":\"# \\\"a\\\"\\n,#\",\"time_ns\":12,\"status\":\"ok\",\"error\":null},",
+            "  {\"day\":17,\"part\":1,\"answer\":null,\"time_ns\":null,\"status\":\"unimplemented\",\"error\":null},",
+            "  {\"day\":18,\"part\":2,\"answer\":null,\"time_ns\":null,\"status\":\"error\",\"error\":\"could not read inputs/day_18.txt\"}",
+            "]",
+        ]
+        .join("\n");
+
+        let result = to_json(&get_test_records());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let expected = [
+            "day,part,answer,time_ns,status,error",
+            "1,1,1553,70384,ok,",
+            "13,2,\"# \"\"a\"\"\n,#\",12,ok,",
+            "17,1,,,unimplemented,",
+            "18,2,,,error,could not read inputs/day_18.txt",
+        ]
+        .join("\n");
+
+        let result = to_csv(&get_test_records());
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,388 @@
+use crate::solution::{Answer, Example, Part, Solver};
+use crate::util::file_reader::{stdin_to_string_vector, to_string_vector};
+use crate::{
+    day_1, day_10, day_11, day_12, day_13, day_14, day_15, day_16, day_17, day_18, day_19, day_2,
+    day_20, day_21, day_22, day_23, day_24, day_25, day_3, day_4, day_5, day_6, day_7, day_8,
+    day_9,
+};
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const NUMBER_OF_DAYS: usize = 25;
+
+static SOLUTIONS: [&dyn Solver; NUMBER_OF_DAYS] = [
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+    &day_9::Day9,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+    &day_21::Day21,
+    &day_22::Day22,
+    &day_23::Day23,
+    &day_24::Day24,
+    &day_25::Day25,
+];
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PartResult {
+    Solved(Answer, Duration),
+    NotImplemented,
+    Failed(String),
+}
+
+impl fmt::Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartResult::Solved(answer, _) => write!(f, "{}", answer),
+            PartResult::NotImplemented => write!(f, "not implemented"),
+            PartResult::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DayResult {
+    pub day: u32,
+    pub parts: Vec<(Part, PartResult)>,
+    pub parse_time: Duration,
+}
+
+pub fn get_solver(day: u32) -> Option<&'static dyn Solver> {
+    let index = (day as usize).checked_sub(1)?;
+
+    SOLUTIONS.get(index).copied()
+}
+
+pub fn get_input_file_name(day: u32) -> String {
+    format!("inputs/day_{}.txt", day)
+}
+
+pub fn get_input(file_name: &str) -> Result<Vec<String>, String> {
+    if file_name == "-" {
+        stdin_to_string_vector()
+    } else {
+        to_string_vector(file_name)
+    }
+    .map_err(|error| error.to_string())
+}
+
+fn get_panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown error")
+    }
+}
+
+fn is_not_implemented_message(message: &str) -> bool {
+    message.starts_with("not implemented") || message.starts_with("not yet implemented")
+}
+
+pub fn run_guarded<T, F>(function: F) -> Result<(T, Duration), PartResult>
+where
+    F: FnOnce() -> Result<T, String>,
+{
+    let start = Instant::now();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(function));
+
+    let elapsed = start.elapsed();
+
+    match result {
+        Ok(Ok(value)) => Ok((value, elapsed)),
+        Ok(Err(error)) => Err(PartResult::Failed(error)),
+        Err(payload) => {
+            let message = get_panic_message(payload.as_ref());
+
+            if is_not_implemented_message(&message) {
+                Err(PartResult::NotImplemented)
+            } else {
+                Err(PartResult::Failed(message))
+            }
+        }
+    }
+}
+
+pub fn run_parts(day: u32, file_name: &str, parts: &[Part]) -> (Vec<(Part, PartResult)>, Duration) {
+    let all_parts_with = |result: PartResult| -> Vec<(Part, PartResult)> {
+        parts.iter().map(|&part| (part, result.clone())).collect()
+    };
+
+    let solver = match get_solver(day) {
+        Some(solver) => solver,
+        None => return (all_parts_with(PartResult::NotImplemented), Duration::ZERO),
+    };
+
+    let input = match run_guarded(|| get_input(file_name)) {
+        Ok((input, _)) => input,
+        Err(error) => return (all_parts_with(error), Duration::ZERO),
+    };
+
+    solve_input(solver, &input, file_name, parts)
+}
+
+fn solve_input(
+    solver: &dyn Solver,
+    input: &[String],
+    source_name: &str,
+    parts: &[Part],
+) -> (Vec<(Part, PartResult)>, Duration) {
+    let all_parts_with = |result: PartResult| -> Vec<(Part, PartResult)> {
+        parts.iter().map(|&part| (part, result.clone())).collect()
+    };
+
+    let (parsed_input, parse_time) = match run_guarded(|| {
+        solver
+            .parse_input(input)
+            .map_err(|error| error.in_file(source_name))
+    }) {
+        Ok(parsed_input) => parsed_input,
+        Err(error) => return (all_parts_with(error), Duration::ZERO),
+    };
+
+    let results = parts
+        .iter()
+        .map(|&part| {
+            let result = match run_guarded(|| solver.solve(&parsed_input, part)) {
+                Ok((answer, elapsed)) => PartResult::Solved(answer, elapsed),
+                Err(error) => error,
+            };
+
+            (part, result)
+        })
+        .collect();
+
+    (results, parse_time)
+}
+
+pub fn run_examples(day: u32, parts: &[Part]) -> Vec<(Example, Vec<(Part, PartResult)>)> {
+    let solver = match get_solver(day) {
+        Some(solver) => solver,
+        None => return Vec::new(),
+    };
+
+    solver
+        .get_examples()
+        .iter()
+        .enumerate()
+        .map(|(index, example)| {
+            let source_name = format!("day {} example {}", day, index + 1);
+
+            let (results, _) = solve_input(solver, &example.get_lines(), &source_name, parts);
+
+            (*example, results)
+        })
+        .collect()
+}
+
+pub fn run_days(days: &[(u32, String)], parts: &[Part], jobs: usize) -> Vec<DayResult> {
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let next_index = &next_index;
+
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+
+                let (day, file_name) = match days.get(index) {
+                    Some(day) => day,
+                    None => break,
+                };
+
+                let (results, parse_time) = run_parts(*day, file_name, parts);
+
+                let day_result = DayResult {
+                    day: *day,
+                    parts: results,
+                    parse_time,
+                };
+
+                if sender.send((index, day_result)).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+
+    drop(sender);
+
+    let mut day_results: Vec<(usize, DayResult)> = receiver.into_iter().collect();
+
+    day_results.sort_by_key(|(index, _)| *index);
+
+    day_results
+        .into_iter()
+        .map(|(_, day_result)| day_result)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_solver() {
+        let expected = vec![false, true, true, false];
+
+        let result: Vec<bool> = [0, 1, 25, 26]
+            .iter()
+            .map(|&day| get_solver(day).is_some())
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_run_parts_missing_input() {
+        let (results, _) = run_parts(25, &get_input_file_name(25), &[Part::One]);
+
+        let expected = vec![(
+            Part::One,
+            PartResult::Failed(String::from("inputs/day_25.txt: file not found")),
+        )];
+
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_run_examples() {
+        let expected = vec![vec![
+            (Part::One, Some(Answer::from(7_u32))),
+            (Part::Two, Some(Answer::from(5_u32))),
+        ]];
+
+        let result: Vec<Vec<(Part, Option<Answer>)>> = run_examples(1, &[Part::One, Part::Two])
+            .into_iter()
+            .map(|(_, results)| {
+                results
+                    .into_iter()
+                    .map(|(part, result)| match result {
+                        PartResult::Solved(answer, _) => (part, Some(answer)),
+                        _ => (part, None),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(result, expected);
+        assert!(run_examples(26, &[Part::One]).is_empty());
+    }
+
+    #[test]
+    fn test_get_solver_diagram() {
+        let solver = get_solver(23).unwrap();
+        let example = &solver.get_examples()[0];
+
+        let parsed_input = solver.parse_input(&example.get_lines()).unwrap();
+
+        let result = solver
+            .get_diagram(&parsed_input, Part::One)
+            .unwrap()
+            .unwrap();
+
+        assert!(result.starts_with("Step 0 (energy 0):\n"));
+        assert!(result.contains("(energy 12521):\n"));
+    }
+
+    #[test]
+    fn test_run_examples_match_expected_answers() {
+        for day in 1..=(NUMBER_OF_DAYS as u32) {
+            for (example, results) in run_examples(day, &[Part::One, Part::Two]) {
+                for (part, result) in results {
+                    if let Some(expected) = example.get_answer(part) {
+                        let actual = match result {
+                            PartResult::Solved(answer, _) => answer.to_string(),
+                            result => format!("{:?}", result),
+                        };
+
+                        assert_eq!(actual, expected, "day {} part {}", day, part);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_days_keeps_day_order() {
+        let days: Vec<(u32, String)> = [25, 1, 24, 6, 2]
+            .iter()
+            .map(|&day| (day, get_input_file_name(day)))
+            .collect();
+
+        let expected: Vec<u32> = days.iter().map(|(day, _)| *day).collect();
+
+        let result: Vec<u32> = run_days(&days, &[Part::One], 3)
+            .iter()
+            .map(|day_result| day_result.day)
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_run_days_matches_run_parts() {
+        let days: Vec<(u32, String)> = [1, 6, 25]
+            .iter()
+            .map(|&day| (day, get_input_file_name(day)))
+            .collect();
+
+        let expected: Vec<Vec<(Part, PartResult)>> = days
+            .iter()
+            .map(|(day, file_name)| without_times(run_parts(*day, file_name, &[Part::Two]).0))
+            .collect();
+
+        let result: Vec<Vec<(Part, PartResult)>> = run_days(&days, &[Part::Two], 4)
+            .into_iter()
+            .map(|day_result| without_times(day_result.parts))
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    fn without_times(results: Vec<(Part, PartResult)>) -> Vec<(Part, PartResult)> {
+        results
+            .into_iter()
+            .map(|(part, result)| match result {
+                PartResult::Solved(answer, _) => (part, PartResult::Solved(answer, Duration::ZERO)),
+                result => (part, result),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_run_guarded_isolates_panics() {
+        let result = thread::scope(|scope| {
+            scope
+                .spawn(|| run_guarded::<u32, _>(|| panic!("boom")))
+                .join()
+                .unwrap()
+        });
+
+        assert_eq!(result.err(), Some(PartResult::Failed(String::from("boom"))));
+    }
+}
@@ -0,0 +1,258 @@
+use crate::util::parse_error::ParseError;
+use std::any::Any;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Answer {
+    pub fn is_multi_line(&self) -> bool {
+        match self {
+            Answer::Number(_) => false,
+            Answer::Text(text) => text.contains('\n'),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($number_type:ty),*) => {
+        $(
+            impl From<$number_type> for Answer {
+                fn from(number: $number_type) -> Answer {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    pub fn get_lines(&self) -> Vec<String> {
+        self.input.lines().map(String::from).collect()
+    }
+
+    pub fn get_answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &[String]) -> Result<Self::Input, ParseError>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, String>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, String>;
+
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    fn diagram(&self, _input: &Self::Input, _part: Part) -> Option<Result<String, String>> {
+        None
+    }
+}
+
+pub type ParsedInput = Box<dyn Any + Send>;
+
+pub trait Solver: Send + Sync {
+    fn parse_input(&self, input: &[String]) -> Result<ParsedInput, ParseError>;
+
+    fn solve(&self, parsed_input: &ParsedInput, part: Part) -> Result<Answer, String>;
+
+    fn get_examples(&self) -> &'static [Example];
+
+    fn get_diagram(&self, parsed_input: &ParsedInput, part: Part)
+        -> Option<Result<String, String>>;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Send + 'static,
+{
+    fn parse_input(&self, input: &[String]) -> Result<ParsedInput, ParseError> {
+        self.parse(input)
+            .map(|parsed_input| Box::new(parsed_input) as ParsedInput)
+    }
+
+    fn solve(&self, parsed_input: &ParsedInput, part: Part) -> Result<Answer, String> {
+        let parsed_input = parsed_input
+            .downcast_ref::<S::Input>()
+            .ok_or_else(|| String::from("Parsed input does not belong to this solution!"))?;
+
+        match part {
+            Part::One => self.part_one(parsed_input),
+            Part::Two => self.part_two(parsed_input),
+        }
+    }
+
+    fn get_examples(&self) -> &'static [Example] {
+        self.examples()
+    }
+
+    fn get_diagram(
+        &self,
+        parsed_input: &ParsedInput,
+        part: Part,
+    ) -> Option<Result<String, String>> {
+        match parsed_input.downcast_ref::<S::Input>() {
+            Some(parsed_input) => self.diagram(parsed_input, part),
+            None => Some(Err(String::from(
+                "Parsed input does not belong to this solution!",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCounter;
+
+    impl Solution for LineCounter {
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &[String]) -> Result<Vec<String>, ParseError> {
+            Ok(input.to_vec())
+        }
+
+        fn part_one(&self, input: &Vec<String>) -> Result<Answer, String> {
+            Ok(Answer::from(input.len()))
+        }
+
+        fn part_two(&self, input: &Vec<String>) -> Result<Answer, String> {
+            Ok(Answer::from(input.join("\n")))
+        }
+    }
+
+    #[test]
+    fn test_answer_display() {
+        let expected = vec!["-42", "18446744073709551615", "ab\ncd"];
+
+        let result: Vec<String> = [
+            Answer::from(-42),
+            Answer::from(u64::MAX),
+            Answer::from("ab\ncd"),
+        ]
+        .iter()
+        .map(|answer| answer.to_string())
+        .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_answer_is_multi_line() {
+        assert!(!Answer::from(12_usize).is_multi_line());
+        assert!(!Answer::from("ab").is_multi_line());
+        assert!(Answer::from("ab\ncd").is_multi_line());
+    }
+
+    #[test]
+    fn test_example() {
+        let example = Example {
+            input: "ab\ncd",
+            part_one: Some("2"),
+            part_two: None,
+        };
+
+        assert_eq!(example.get_lines(), vec!["ab", "cd"]);
+        assert_eq!(example.get_answer(Part::One), Some("2"));
+        assert_eq!(example.get_answer(Part::Two), None);
+    }
+
+    #[test]
+    fn test_solver_solve() {
+        let solver: &dyn Solver = &LineCounter;
+
+        let parsed_input = solver
+            .parse_input(&[String::from("ab"), String::from("cd")])
+            .unwrap();
+
+        let expected = vec![Ok(Answer::from(2_usize)), Ok(Answer::from("ab\ncd"))];
+
+        let result = vec![
+            solver.solve(&parsed_input, Part::One),
+            solver.solve(&parsed_input, Part::Two),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_solver_solve_wrong_input() {
+        let solver: &dyn Solver = &LineCounter;
+
+        let parsed_input: ParsedInput = Box::new(7_u32);
+
+        assert!(solver.solve(&parsed_input, Part::One).is_err());
+    }
+
+    #[test]
+    fn test_solver_get_diagram() {
+        let solver: &dyn Solver = &LineCounter;
+
+        let parsed_input = solver.parse_input(&[String::from("ab")]).unwrap();
+        let wrong_input: ParsedInput = Box::new(7_u32);
+
+        assert_eq!(solver.get_diagram(&parsed_input, Part::One), None);
+        assert!(matches!(
+            solver.get_diagram(&wrong_input, Part::One),
+            Some(Err(_))
+        ));
+    }
+}
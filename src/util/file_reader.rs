@@ -1,17 +1,149 @@
+use crate::util::parse_error::{parse_number, ParseError};
+use std::error::Error;
+use std::fmt;
 use std::fs;
+use std::io::{self, ErrorKind, Read};
+use std::str::FromStr;
 
-pub fn to_string_vector(file_name: &str) -> Result<Vec<String>, String> {
-    Ok(fs::read_to_string(file_name)
-        .expect("File not found!")
-        .trim_end()
-        .split("\n")
-        .map(|line| line.to_string())
-        .collect())
+const STDIN_NAME: &str = "<stdin>";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FileReaderError {
+    NotFound(String),
+    Unreadable(String, String),
+    EmptyInput(String),
+}
+
+impl fmt::Display for FileReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FileReaderError::NotFound(file_name) => write!(f, "{}: file not found", file_name),
+            FileReaderError::Unreadable(file_name, reason) => {
+                write!(f, "{}: could not read file: {}", file_name, reason)
+            }
+            FileReaderError::EmptyInput(file_name) => write!(f, "{}: input is empty", file_name),
+        }
+    }
+}
+
+impl Error for FileReaderError {}
+
+pub fn to_string_vector(file_name: &str) -> Result<Vec<String>, FileReaderError> {
+    let contents = fs::read_to_string(file_name).map_err(|error| match error.kind() {
+        ErrorKind::NotFound => FileReaderError::NotFound(file_name.to_string()),
+        _ => FileReaderError::Unreadable(file_name.to_string(), error.to_string()),
+    })?;
+
+    split_lines(file_name, &contents)
+}
+
+pub fn stdin_to_string_vector() -> Result<Vec<String>, FileReaderError> {
+    reader_to_string_vector(io::stdin())
+}
+
+pub fn reader_to_string_vector<R: Read>(mut reader: R) -> Result<Vec<String>, FileReaderError> {
+    let mut contents = String::new();
+
+    reader
+        .read_to_string(&mut contents)
+        .map_err(|error| FileReaderError::Unreadable(STDIN_NAME.to_string(), error.to_string()))?;
+
+    split_lines(STDIN_NAME, &contents)
+}
+
+pub fn get_single_line(lines: &[String]) -> Result<&str, ParseError> {
+    match lines {
+        [line] => Ok(line),
+        [] => Err(ParseError::new(
+            "",
+            "expected a single line, but the input is empty",
+        )),
+        _ => Err(ParseError::new(
+            &lines[1],
+            &format!("expected a single line, but got {} lines", lines.len()),
+        )
+        .at_line(2)),
+    }
+}
+
+pub fn get_comma_separated_numbers<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    let mut column = 1;
+
+    line.split(',')
+        .map(|split| {
+            let number = parse_number(split).map_err(|error| error.at_column(column));
+
+            column += split.len() + 1;
+
+            number
+        })
+        .collect()
+}
+
+pub fn get_digit_grid(lines: &[String]) -> Result<Vec<Vec<u32>>, ParseError> {
+    let width = lines.first().map_or(0, |line| line.len());
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_index, line)| {
+            if line.len() != width {
+                return Err(ParseError::new(line, &format!("expected {} digits", width))
+                    .at_line(line_index + 1));
+            }
+
+            line.chars()
+                .enumerate()
+                .map(|(column_index, character)| {
+                    character.to_digit(10).ok_or_else(|| {
+                        ParseError::new(line, &format!("expected a digit, but got '{}'", character))
+                            .at_line(line_index + 1)
+                            .at_column(column_index + 1)
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn get_sections(lines: &[String]) -> Vec<Vec<String>> {
+    get_indexed_sections(lines)
+        .into_iter()
+        .map(|(_, section)| section.to_vec())
+        .collect()
+}
+
+pub fn get_indexed_sections(lines: &[String]) -> Vec<(usize, &[String])> {
+    let mut sections = Vec::new();
+    let mut section_start = 0;
+
+    for (line_index, line) in lines.iter().chain([String::new()].iter()).enumerate() {
+        if line.trim().is_empty() {
+            if section_start < line_index {
+                sections.push((section_start, &lines[section_start..line_index]));
+            }
+
+            section_start = line_index + 1;
+        }
+    }
+
+    sections
+}
+
+fn split_lines(file_name: &str, contents: &str) -> Result<Vec<String>, FileReaderError> {
+    let contents = contents.trim_end();
+
+    if contents.is_empty() {
+        return Err(FileReaderError::EmptyInput(file_name.to_string()));
+    }
+
+    Ok(contents.lines().map(|line| line.to_string()).collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::test_tools::str_slice_to_string_vector;
 
     #[test]
     fn test_to_string_vector() {
@@ -30,8 +162,113 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    fn test_reader_to_string_vector() {
+        let expected = vec![String::from("1721"), String::from("979")];
+
+        let result = reader_to_string_vector("1721\n979\n".as_bytes()).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_reader_to_string_vector_crlf() {
+        let expected = vec![String::from("1721"), String::new(), String::from("979")];
+
+        let result = reader_to_string_vector("1721\r\n\r\n979\r\n".as_bytes()).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_reader_to_string_vector_empty() {
+        let expected = Err(FileReaderError::EmptyInput(STDIN_NAME.to_string()));
+
+        let result = reader_to_string_vector(" \r\n\n".as_bytes());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
     fn test_to_string_vector_error() {
-        let _result = to_string_vector("test_inputs/day_100000000.txt");
+        let expected = Err(FileReaderError::NotFound(String::from(
+            "test_inputs/day_100000000.txt",
+        )));
+
+        let result = to_string_vector("test_inputs/day_100000000.txt");
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_single_line() {
+        let input = str_slice_to_string_vector(&["3,4,3,1,2"]);
+
+        assert_eq!(get_single_line(&input), Ok("3,4,3,1,2"));
+        assert!(get_single_line(&[]).is_err());
+        assert!(get_single_line(&str_slice_to_string_vector(&["1", "2"])).is_err());
+    }
+
+    #[test]
+    fn test_get_comma_separated_numbers() {
+        let expected: Result<Vec<i32>, ParseError> = Ok(vec![16, 1, 2, 0, -4]);
+
+        let result = get_comma_separated_numbers("16,1, 2,0,-4");
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            get_comma_separated_numbers::<u32>("1,,2").map_err(|error| error.column),
+            Err(Some(3))
+        );
+    }
+
+    #[test]
+    fn test_get_digit_grid() {
+        let input = str_slice_to_string_vector(&["2199", "3987"]);
+
+        let expected = Ok(vec![vec![2, 1, 9, 9], vec![3, 9, 8, 7]]);
+
+        let result = get_digit_grid(&input);
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            get_digit_grid(&str_slice_to_string_vector(&["12", "3x"]))
+                .unwrap_err()
+                .to_string(),
+            "2:2: expected a digit, but got 'x'"
+        );
+        assert_eq!(
+            get_digit_grid(&str_slice_to_string_vector(&["12", "345"]))
+                .unwrap_err()
+                .to_string(),
+            "2: expected 2 digits"
+        );
+    }
+
+    #[test]
+    fn test_get_sections() {
+        let input = str_slice_to_string_vector(&["NNCB", "", "CH -> B", "HH -> N", "", ""]);
+
+        let expected = vec![
+            str_slice_to_string_vector(&["NNCB"]),
+            str_slice_to_string_vector(&["CH -> B", "HH -> N"]),
+        ];
+
+        let result = get_sections(&input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_indexed_sections() {
+        let input = str_slice_to_string_vector(&["", "NNCB", "", "CH -> B", "HH -> N"]);
+
+        let expected = vec![1, 3];
+
+        let result: Vec<usize> = get_indexed_sections(&input)
+            .iter()
+            .map(|(line_index, _)| *line_index)
+            .collect();
+
+        assert_eq!(result, expected);
     }
 }
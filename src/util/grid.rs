@@ -0,0 +1,329 @@
+use crate::util::file_reader::get_digit_grid;
+use crate::util::parse_error::ParseError;
+use crate::util::point_2d::Point2d;
+use std::fmt;
+
+const ORTHOGONAL_OFFSETS: [Point2d<i32>; 4] = [
+    Point2d { x: 0, y: -1 },
+    Point2d { x: 1, y: 0 },
+    Point2d { x: 0, y: 1 },
+    Point2d { x: -1, y: 0 },
+];
+
+const ALL_OFFSETS: [Point2d<i32>; 8] = [
+    Point2d { x: 0, y: -1 },
+    Point2d { x: 1, y: -1 },
+    Point2d { x: 1, y: 0 },
+    Point2d { x: 1, y: 1 },
+    Point2d { x: 0, y: 1 },
+    Point2d { x: -1, y: 1 },
+    Point2d { x: -1, y: 0 },
+    Point2d { x: -1, y: -1 },
+];
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid {
+            cells: Vec::new(),
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F>(width: usize, height: usize, mut cell_at: F) -> Grid<T>
+    where
+        F: FnMut(Point2d<i32>) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point2d::new(x as i32, y as i32)))
+            .map(&mut cell_at)
+            .collect();
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (row_index, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::new(
+                    "",
+                    &format!("expected {} columns, but got {}", width, row.len()),
+                )
+                .at_line(row_index + 1));
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn from_char_lines<F>(lines: &[String], parse_char: F) -> Result<Grid<T>, ParseError>
+    where
+        F: Fn(char) -> Result<T, ParseError>,
+    {
+        let rows = lines
+            .iter()
+            .enumerate()
+            .map(|(line_index, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(column_index, character)| {
+                        parse_char(character).map_err(|error| {
+                            error.at_line(line_index + 1).at_column(column_index + 1)
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, ParseError>>()?;
+
+        Grid::from_rows(rows)
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point2d<i32>) -> bool {
+        (0..self.width as i32).contains(&point.x) && (0..self.height as i32).contains(&point.y)
+    }
+
+    pub fn get(&self, point: &Point2d<i32>) -> Option<&T> {
+        self.get_index(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: &Point2d<i32>) -> Option<&mut T> {
+        self.get_index(point)
+            .map(move |index| &mut self.cells[index])
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point2d<i32>> {
+        let width = self.width;
+
+        (0..self.cells.len())
+            .map(move |index| Point2d::new((index % width) as i32, (index / width) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2d<i32>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point2d<i32>, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    pub fn get_neighbors<'a>(
+        &'a self,
+        point: &'a Point2d<i32>,
+    ) -> impl Iterator<Item = Point2d<i32>> + 'a {
+        self.get_neighbors_at(point, &ORTHOGONAL_OFFSETS)
+    }
+
+    pub fn get_neighbors_with_diagonals<'a>(
+        &'a self,
+        point: &'a Point2d<i32>,
+    ) -> impl Iterator<Item = Point2d<i32>> + 'a {
+        self.get_neighbors_at(point, &ALL_OFFSETS)
+    }
+
+    pub fn map<U, F>(&self, map_cell: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(map_cell).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn get_neighbors_at<'a>(
+        &'a self,
+        point: &'a Point2d<i32>,
+        offsets: &'static [Point2d<i32>],
+    ) -> impl Iterator<Item = Point2d<i32>> + 'a {
+        offsets
+            .iter()
+            .map(move |offset| *point + *offset)
+            .filter(move |neighbor| self.contains(neighbor))
+    }
+
+    fn get_index(&self, point: &Point2d<i32>) -> Option<usize> {
+        if self.contains(point) {
+            Some(point.y as usize * self.width + point.x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl Grid<u32> {
+    pub fn from_digit_lines(lines: &[String]) -> Result<Grid<u32>, ParseError> {
+        Grid::from_rows(get_digit_grid(lines)?)
+    }
+}
+
+impl<T> fmt::Display for Grid<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (row_index, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            if row_index > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    const TEST_DATA: [&str; 3] = ["123", "456", "789"];
+
+    fn get_test_grid() -> Grid<u32> {
+        Grid::from_digit_lines(&str_slice_to_string_vector(&TEST_DATA)).unwrap()
+    }
+
+    #[test]
+    fn test_from_digit_lines() {
+        let grid = get_test_grid();
+
+        assert_eq!(grid.get_width(), 3);
+        assert_eq!(grid.get_height(), 3);
+        assert_eq!(grid.get(&Point2d::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(&Point2d::new(3, 1)), None);
+        assert_eq!(grid.get(&Point2d::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_from_char_lines_error() {
+        let input = str_slice_to_string_vector(&["#.", ".x"]);
+
+        let result = Grid::from_char_lines(&input, |character| match character {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new("", "expected '#' or '.'")),
+        })
+        .map_err(|error| error.to_string());
+
+        assert_eq!(result, Err(String::from("2:2: expected '#' or '.'")));
+    }
+
+    #[test]
+    fn test_from_rows_error() {
+        let result = Grid::from_rows(vec![vec![1, 2], vec![3]]).map_err(|error| error.to_string());
+
+        assert_eq!(
+            result,
+            Err(String::from("2: expected 2 columns, but got 1"))
+        );
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let expected = Grid::from_rows(vec![vec![0, 1, 2], vec![1, 2, 3]]).unwrap();
+
+        let result = Grid::from_fn(3, 2, |point| point.x + point.y);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_neighbors() {
+        let grid = get_test_grid();
+
+        let expected_corner = vec![Point2d::new(1, 0), Point2d::new(0, 1)];
+        let expected_center_count = 4;
+
+        let result_corner: Vec<Point2d<i32>> = grid.get_neighbors(&Point2d::new(0, 0)).collect();
+        let result_center_count = grid.get_neighbors(&Point2d::new(1, 1)).count();
+
+        assert_eq!(result_corner, expected_corner);
+        assert_eq!(result_center_count, expected_center_count);
+    }
+
+    #[test]
+    fn test_get_neighbors_with_diagonals() {
+        let grid = get_test_grid();
+
+        let expected: Vec<u32> = vec![2, 5, 4];
+
+        let result: Vec<u32> = grid
+            .get_neighbors_with_diagonals(&Point2d::new(0, 0))
+            .map(|point| *grid.get(&point).unwrap())
+            .collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            grid.get_neighbors_with_diagonals(&Point2d::new(1, 1))
+                .count(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_iter_and_get_mut() {
+        let mut grid = get_test_grid();
+
+        *grid.get_mut(&Point2d::new(1, 2)).unwrap() = 0;
+
+        let result: Vec<(Point2d<i32>, u32)> = grid
+            .iter()
+            .filter(|(_, &value)| value % 4 == 0)
+            .map(|(point, &value)| (point, value))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![(Point2d::new(0, 1), 4), (Point2d::new(1, 2), 0)]
+        );
+    }
+
+    #[test]
+    fn test_map_display() {
+        let grid = get_test_grid();
+
+        let expected = "#.#\n.#.\n#.#";
+
+        let result = grid
+            .map(|value| if value % 2 == 1 { '#' } else { '.' })
+            .to_string();
+
+        assert_eq!(result, expected);
+    }
+}
@@ -1,5 +1,5 @@
-use std::cmp::Ordering;
-
+use super::location::Location;
+use super::math::gcd;
 use super::point_2d::Point2d;
 
 #[derive(Debug, Eq, PartialEq, PartialOrd, Hash, Copy, Clone, Ord)]
@@ -8,6 +8,12 @@ pub struct Line2d {
     pub end: Point2d<i32>,
 }
 
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum LineIntersection {
+    Point(Point2d<i32>),
+    Segment(Line2d),
+}
+
 impl Line2d {
     pub fn new(start: (i32, i32), end: (i32, i32)) -> Line2d {
         Line2d {
@@ -16,27 +22,35 @@ impl Line2d {
         }
     }
 
+    /// Rasterizes the line with Bresenham's algorithm, i.e. the grid cells a drawn line would cover.
+    /// Off 0°, 45° and 90° these are approximations, so use `get_lattice_points` for exact points.
     pub fn int_points_along_line(&self) -> Vec<Point2d<i32>> {
         let mut result = Vec::new();
 
+        let delta_x = (self.end.x - self.start.x).abs();
+        let delta_y = -(self.end.y - self.start.y).abs();
+        let step = Point2d::new(
+            (self.end.x - self.start.x).signum(),
+            (self.end.y - self.start.y).signum(),
+        );
+
         let mut current_point = self.start;
+        let mut error = delta_x + delta_y;
 
         while current_point != self.end {
             result.push(current_point);
 
-            let new_x = match current_point.x.cmp(&self.end.x) {
-                Ordering::Less => current_point.x + 1,
-                Ordering::Greater => current_point.x - 1,
-                Ordering::Equal => current_point.x,
-            };
+            let doubled_error = 2 * error;
 
-            let new_y = match current_point.y.cmp(&self.end.y) {
-                Ordering::Less => current_point.y + 1,
-                Ordering::Greater => current_point.y - 1,
-                Ordering::Equal => current_point.y,
-            };
+            if doubled_error >= delta_y {
+                error += delta_y;
+                current_point.x += step.x;
+            }
 
-            current_point = Point2d::new(new_x, new_y);
+            if doubled_error <= delta_x {
+                error += delta_x;
+                current_point.y += step.y;
+            }
         }
 
         result.push(self.end);
@@ -44,6 +58,86 @@ impl Line2d {
         result
     }
 
+    /// Returns the integer points that lie exactly on the line, which is what
+    /// `get_int_intersection` agrees with.
+    pub fn get_lattice_points(&self) -> Vec<Point2d<i32>> {
+        let direction = self.end - self.start;
+
+        let number_of_steps = match (direction.x.abs(), direction.y.abs()) {
+            (0, steps) | (steps, 0) => steps,
+            (x_steps, y_steps) => gcd(x_steps, y_steps),
+        };
+
+        if number_of_steps == 0 {
+            return vec![self.start];
+        }
+
+        let step = direction / number_of_steps;
+
+        (0..=number_of_steps)
+            .map(|index| self.start + step * index)
+            .collect()
+    }
+
+    pub fn get_length(&self) -> f64 {
+        self.start.distance_to(&self.end)
+    }
+
+    pub fn contains(&self, point: &Point2d<i32>) -> bool {
+        let is_collinear = cross(self.end - self.start, *point - self.start) == 0;
+
+        is_collinear
+            && (self.start.x.min(self.end.x)..=self.start.x.max(self.end.x)).contains(&point.x)
+            && (self.start.y.min(self.end.y)..=self.start.y.max(self.end.y)).contains(&point.y)
+    }
+
+    pub fn get_int_intersection(&self, other: &Line2d) -> Option<LineIntersection> {
+        let direction = self.end - self.start;
+        let other_direction = other.end - other.start;
+        let relative_start = other.start - self.start;
+
+        let denominator = cross(direction, other_direction);
+
+        if denominator == 0 {
+            return self.get_collinear_overlap(other);
+        }
+
+        let (numerator, other_numerator, denominator) = if denominator > 0 {
+            (
+                cross(relative_start, other_direction),
+                cross(relative_start, direction),
+                denominator,
+            )
+        } else {
+            (
+                -cross(relative_start, other_direction),
+                -cross(relative_start, direction),
+                -denominator,
+            )
+        };
+
+        let range = 0..=denominator;
+
+        if !range.contains(&numerator) || !range.contains(&other_numerator) {
+            return None;
+        }
+
+        let offset_x = numerator * direction.x as i128;
+        let offset_y = numerator * direction.y as i128;
+
+        if offset_x % denominator != 0 || offset_y % denominator != 0 {
+            return None;
+        }
+
+        let point = self.start
+            + Point2d::new(
+                (offset_x / denominator) as i32,
+                (offset_y / denominator) as i32,
+            );
+
+        Some(LineIntersection::Point(point))
+    }
+
     pub fn is_vertical(&self) -> bool {
         self.start.x == self.end.x
     }
@@ -51,11 +145,55 @@ impl Line2d {
     pub fn is_horizontal(&self) -> bool {
         self.start.y == self.end.y
     }
+
+    fn get_collinear_overlap(&self, other: &Line2d) -> Option<LineIntersection> {
+        let direction = self.end - self.start;
+        let position_along_line = |point: &Point2d<i32>| dot(*point - self.start, direction);
+
+        let shared_points: Vec<Point2d<i32>> = [self.start, self.end, other.start, other.end]
+            .into_iter()
+            .filter(|point| self.contains(point) && other.contains(point))
+            .collect();
+
+        let first = *shared_points
+            .iter()
+            .min_by_key(|point| position_along_line(point))?;
+        let last = *shared_points
+            .iter()
+            .max_by_key(|point| position_along_line(point))?;
+
+        if first == last {
+            Some(LineIntersection::Point(first))
+        } else {
+            Some(LineIntersection::Segment(Line2d {
+                start: first,
+                end: last,
+            }))
+        }
+    }
+}
+
+fn cross(first: Point2d<i32>, second: Point2d<i32>) -> i128 {
+    first.x as i128 * second.y as i128 - first.y as i128 * second.x as i128
+}
+
+fn dot(first: Point2d<i32>, second: Point2d<i32>) -> i128 {
+    first.x as i128 * second.x as i128 + first.y as i128 * second.y as i128
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+
+    const EPSILON: f64 = 1e-10;
+
+    fn to_points(coordinates: &[(i32, i32)]) -> Vec<Point2d<i32>> {
+        coordinates
+            .iter()
+            .map(|&point| Point2d::from(point))
+            .collect()
+    }
 
     #[test]
     fn test_int_points_along_line() {
@@ -75,4 +213,150 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_int_points_along_line_diagonal() {
+        let test_line = Line2d::new((5, 5), (8, 2));
+
+        let expected = to_points(&[(5, 5), (6, 4), (7, 3), (8, 2)]);
+
+        let result = test_line.int_points_along_line();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_int_points_along_line_arbitrary_slope() {
+        let shallow_line = Line2d::new((0, 0), (5, 2));
+        let steep_line = Line2d::new((1, 5), (0, 0));
+
+        let expected_shallow = to_points(&[(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)]);
+        let expected_steep = to_points(&[(1, 5), (1, 4), (1, 3), (0, 2), (0, 1), (0, 0)]);
+
+        let result_shallow = shallow_line.int_points_along_line();
+        let result_steep = steep_line.int_points_along_line();
+
+        assert_eq!(result_shallow, expected_shallow);
+        assert_eq!(result_steep, expected_steep);
+    }
+
+    #[test]
+    fn test_get_lattice_points() {
+        let test_line = Line2d::new((6, 3), (0, 0));
+
+        let expected = to_points(&[(6, 3), (4, 2), (2, 1), (0, 0)]);
+
+        let result = test_line.get_lattice_points();
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            Line2d::new((2, 2), (2, 2)).get_lattice_points(),
+            vec![Point2d::new(2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_get_length() {
+        let test_line = Line2d::new((1, 1), (4, 5));
+
+        let expected = 5.0;
+
+        let result = test_line.get_length();
+
+        assert!((result - expected).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_contains() {
+        let test_line = Line2d::new((0, 0), (6, 3));
+
+        assert!(test_line.contains(&Point2d::new(4, 2)));
+        assert!(test_line.contains(&Point2d::new(6, 3)));
+        assert!(!test_line.contains(&Point2d::new(3, 1)));
+        assert!(!test_line.contains(&Point2d::new(8, 4)));
+    }
+
+    #[test]
+    fn test_get_int_intersection_point() {
+        let first = Line2d::new((0, 0), (8, 8));
+        let second = Line2d::new((8, 0), (0, 8));
+
+        let expected = Some(LineIntersection::Point(Point2d::new(4, 4)));
+
+        let result = first.get_int_intersection(&second);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_int_intersection_point_large_coordinates() {
+        let max = i32::MAX - 1;
+
+        let first = Line2d::new((0, 0), (max, max));
+        let second = Line2d::new((max, 0), (0, max));
+
+        let expected = Some(LineIntersection::Point(Point2d::new(max / 2, max / 2)));
+
+        let result = first.get_int_intersection(&second);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_int_intersection_none() {
+        let crossing_between_points = (Line2d::new((0, 0), (1, 1)), Line2d::new((1, 0), (0, 1)));
+        let disjoint = (Line2d::new((0, 0), (2, 0)), Line2d::new((3, 0), (5, 0)));
+        let parallel = (Line2d::new((0, 0), (2, 0)), Line2d::new((0, 1), (2, 1)));
+        let out_of_reach = (Line2d::new((0, 0), (2, 2)), Line2d::new((0, 9), (9, 9)));
+
+        for (first, second) in [crossing_between_points, disjoint, parallel, out_of_reach] {
+            assert_eq!(first.get_int_intersection(&second), None);
+            assert_eq!(second.get_int_intersection(&first), None);
+        }
+    }
+
+    #[test]
+    fn test_get_int_intersection_segment() {
+        let first = Line2d::new((0, 9), (5, 9));
+        let second = Line2d::new((2, 9), (0, 9));
+        let touching = Line2d::new((5, 9), (5, 0));
+
+        let expected = Some(LineIntersection::Segment(Line2d::new((0, 9), (2, 9))));
+        let expected_touching = Some(LineIntersection::Point(Point2d::new(5, 9)));
+
+        let result = first.get_int_intersection(&second);
+        let result_touching = first.get_int_intersection(&touching);
+
+        assert_eq!(result, expected);
+        assert_eq!(result_touching, expected_touching);
+    }
+
+    #[test]
+    fn test_get_int_intersection_matches_lattice_points() {
+        let line_pairs = [
+            (Line2d::new((0, 0), (6, 3)), Line2d::new((8, 4), (2, 1))),
+            (Line2d::new((0, 0), (4, 2)), Line2d::new((0, 2), (4, 0))),
+            (Line2d::new((1, 0), (1, 9)), Line2d::new((0, 0), (3, 9))),
+        ];
+
+        for (first, second) in line_pairs {
+            let second_points = second.get_lattice_points();
+
+            let expected: HashSet<Point2d<i32>> = first
+                .get_lattice_points()
+                .into_iter()
+                .filter(|point| second_points.contains(point))
+                .collect();
+
+            let result: HashSet<Point2d<i32>> = match first.get_int_intersection(&second) {
+                Some(LineIntersection::Point(point)) => HashSet::from([point]),
+                Some(LineIntersection::Segment(overlap)) => {
+                    overlap.get_lattice_points().into_iter().collect()
+                }
+                None => HashSet::new(),
+            };
+
+            assert_eq!(result, expected);
+        }
+    }
 }
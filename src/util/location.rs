@@ -3,7 +3,18 @@ pub trait Location<RHS = Self> {
 
     fn manhattan_distance_to(&self, other: &RHS) -> Self::ValueOutput;
 
-    fn distance_to(&self, other: &RHS) -> f64;
+    fn chebyshev_distance_to(&self, other: &RHS) -> Self::ValueOutput;
+
+    fn squared_distance_to(&self, other: &RHS) -> Self::ValueOutput;
+
+    fn distance_to(&self, other: &RHS) -> f64
+    where
+        Self::ValueOutput: Into<f64>,
+    {
+        self.squared_distance_to(other).into().sqrt()
+    }
 
     fn add(&self, other: &RHS) -> Self;
+
+    fn sub(&self, other: &RHS) -> Self;
 }
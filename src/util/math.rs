@@ -1,4 +1,4 @@
-use std::ops::{Div, Mul, Rem};
+use std::ops::{Div, Mul, Rem, Sub};
 
 pub fn gcd<T>(first: T, second: T) -> T
 where
@@ -33,3 +33,14 @@ where
 {
     first * (second / gcd(first, second))
 }
+
+pub fn abs_difference<T>(first: T, second: T) -> T
+where
+    T: Sub<Output = T> + Ord + Copy,
+{
+    if first > second {
+        first - second
+    } else {
+        second - first
+    }
+}
@@ -1,8 +1,12 @@
 pub mod file_reader;
+pub mod grid;
 pub mod line_2d;
 pub mod location;
 pub mod math;
+pub mod parse_error;
 pub mod point_2d;
 pub mod point_3d;
+pub mod search;
 pub mod sum_finder;
+#[cfg(test)]
 pub mod test_tools;
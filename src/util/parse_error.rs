@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, message: &str) -> ParseError {
+        ParseError {
+            line: None,
+            column: None,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn expected(text: &str, format: &str) -> ParseError {
+        ParseError::new(text, &format!("expected '{}'", format))
+    }
+
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        self.line.get_or_insert(line);
+
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> ParseError {
+        self.column.get_or_insert(column);
+
+        self
+    }
+
+    pub fn offset_lines(mut self, offset: usize) -> ParseError {
+        self.line = self.line.map(|line| line + offset);
+
+        self
+    }
+
+    pub fn in_file(&self, file_name: &str) -> String {
+        match self.line {
+            Some(_) => format!("{}:{}", file_name, self),
+            None => format!("{}: {}", file_name, self),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{}:{}: {}", line, column, self.message),
+            (Some(line), None) => write!(f, "{}: {}", line, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.trim()
+        .parse()
+        .map_err(|_| ParseError::new(text, &format!("expected a number, but got '{}'", text)))
+}
+
+pub fn parse_lines<T>(lines: &[String]) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_with(lines, str::parse)
+}
+
+pub fn parse_lines_with<T, F>(lines: &[String], parser: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_index, line)| parser(line).map_err(|error| error.at_line(line_index + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_tools::str_slice_to_string_vector;
+
+    #[derive(Debug)]
+    struct Number(u32);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(input: &str) -> Result<Number, ParseError> {
+            parse_number(input).map(Number)
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let expected = vec![
+            "expected 'x1,y1 -> x2,y2'",
+            "17: expected 'x1,y1 -> x2,y2'",
+            "17:4: expected 'x1,y1 -> x2,y2'",
+        ];
+
+        let error = ParseError::expected("0,9 => 5,9", "x1,y1 -> x2,y2");
+
+        let result = vec![
+            error.to_string(),
+            error.clone().at_line(17).to_string(),
+            error.at_line(17).at_column(4).to_string(),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_in_file() {
+        let error = ParseError::expected("", "x1,y1 -> x2,y2");
+
+        assert_eq!(
+            error.clone().at_line(17).in_file("inputs/day_5.txt"),
+            "inputs/day_5.txt:17: expected 'x1,y1 -> x2,y2'"
+        );
+        assert_eq!(
+            error.in_file("inputs/day_5.txt"),
+            "inputs/day_5.txt: expected 'x1,y1 -> x2,y2'"
+        );
+    }
+
+    #[test]
+    fn test_at_line_keeps_innermost_location() {
+        let error = ParseError::new("x", "oops")
+            .at_line(2)
+            .at_line(7)
+            .offset_lines(10);
+
+        assert_eq!(error.line, Some(12));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let expected: Result<Vec<u32>, ParseError> = Ok(vec![199, 200]);
+
+        let result = parse_lines::<Number>(&str_slice_to_string_vector(&["199", "200"]))
+            .map(|numbers| numbers.into_iter().map(|Number(number)| number).collect());
+
+        assert_eq!(result, expected);
+
+        let error =
+            parse_lines::<Number>(&str_slice_to_string_vector(&["199", "2x0"])).unwrap_err();
+
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.text, "2x0");
+    }
+}
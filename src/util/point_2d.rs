@@ -1,6 +1,10 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+use std::str::FromStr;
 
 use super::location::Location;
+use super::math::abs_difference;
+use super::parse_error::{parse_number, ParseError};
 
 #[derive(Debug, Eq, PartialEq, PartialOrd, Hash, Copy, Clone, Ord)]
 pub struct Point2d<T>
@@ -45,26 +49,180 @@ where
     type ValueOutput = T;
 
     fn manhattan_distance_to(&self, other: &Point2d<T>) -> T {
-        let relative_x = other.x - self.x;
-        let relative_y = other.y - self.y;
-
-        relative_x + relative_y
+        abs_difference(self.x, other.x) + abs_difference(self.y, other.y)
     }
 
-    fn distance_to(&self, other: &Point2d<T>) -> f64 {
-        let relative_x = other.x - self.x;
-        let relative_y = other.y - self.y;
+    fn chebyshev_distance_to(&self, other: &Point2d<T>) -> T {
+        abs_difference(self.x, other.x).max(abs_difference(self.y, other.y))
+    }
 
-        let temp = (relative_x * relative_x + relative_y * relative_y).into();
+    fn squared_distance_to(&self, other: &Point2d<T>) -> T {
+        let relative_x = abs_difference(self.x, other.x);
+        let relative_y = abs_difference(self.y, other.y);
 
-        temp.sqrt()
+        relative_x * relative_x + relative_y * relative_y
     }
 
     fn add(&self, other: &Point2d<T>) -> Point2d<T> {
-        let new_x = self.x + other.x;
-        let new_y = self.y + other.y;
+        *self + *other
+    }
+
+    fn sub(&self, other: &Point2d<T>) -> Point2d<T> {
+        *self - *other
+    }
+}
+
+impl<T> Add for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy,
+{
+    type Output = Point2d<T>;
+
+    fn add(self, other: Point2d<T>) -> Point2d<T> {
+        Point2d::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T> AddAssign for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy,
+{
+    fn add_assign(&mut self, other: Point2d<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T> Sub for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy,
+{
+    type Output = Point2d<T>;
+
+    fn sub(self, other: Point2d<T>) -> Point2d<T> {
+        Point2d::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T> Neg for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy,
+{
+    type Output = Point2d<T>;
+
+    fn neg(self) -> Point2d<T> {
+        Point2d::new(-self.x, -self.y)
+    }
+}
+
+impl<T> Mul<T> for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy,
+{
+    type Output = Point2d<T>;
 
-        Point2d::new(new_x, new_y)
+    fn mul(self, scalar: T) -> Point2d<T> {
+        Point2d::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T> Div<T> for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy,
+{
+    type Output = Point2d<T>;
+
+    fn div(self, scalar: T) -> Point2d<T> {
+        Point2d::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl<T> From<(T, T)> for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy,
+{
+    fn from((x, y): (T, T)) -> Point2d<T> {
+        Point2d::new(x, y)
+    }
+}
+
+impl<T> fmt::Display for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy
+        + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T> FromStr for Point2d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Into<f64>
+        + Copy
+        + FromStr,
+{
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Point2d<T>, ParseError> {
+        let (x_str, y_str) = input
+            .split_once(',')
+            .ok_or_else(|| ParseError::expected(input, "x,y"))?;
+
+        Ok(Point2d::new(parse_number(x_str)?, parse_number(y_str)?))
     }
 }
 
@@ -88,6 +246,51 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_manhattan_distance_to_negative_deltas() {
+        let point = Point2d::new(5, -3);
+
+        let expected = 8;
+
+        let result = point.manhattan_distance_to(&ORIGIN_POINT);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_manhattan_distance_to_unsigned() {
+        let first = Point2d::new(2_u32, 7);
+        let second = Point2d::new(5_u32, 1);
+
+        let expected = 9;
+
+        let result = first.manhattan_distance_to(&second);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_chebyshev_distance_to() {
+        let point = Point2d::new(-3, 7);
+
+        let expected = 7;
+
+        let result = ORIGIN_POINT.chebyshev_distance_to(&point);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_squared_distance_to() {
+        let point = Point2d::new(-3, -4);
+
+        let expected = 25;
+
+        let result = point.squared_distance_to(&ORIGIN_POINT);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_distance_to() {
         let point = Point2d::new(3, 4);
@@ -106,7 +309,108 @@ mod tests {
 
         let expected = Point2d::new(8, 3);
 
-        let result = first.add(&second);
+        let result = first + second;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_assign() {
+        let mut point = Point2d::new(3, 4);
+
+        let expected = Point2d::new(8, 3);
+
+        point += Point2d::new(5, -1);
+
+        assert_eq!(point, expected);
+    }
+
+    #[test]
+    fn test_sub() {
+        let first = Point2d::new(3, 4);
+        let second = Point2d::new(5, -1);
+
+        let expected = Point2d::new(-2, 5);
+
+        let result = first - second;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_neg() {
+        let point = Point2d::new(3, -4);
+
+        let expected = Point2d::new(-3, 4);
+
+        let result = -point;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_scalar_mul_div() {
+        let point = Point2d::new(3, -4);
+
+        let expected_product = Point2d::new(6, -8);
+        let expected_quotient = Point2d::new(1, -2);
+
+        let result_product = point * 2;
+        let result_quotient = point / 2;
+
+        assert_eq!(result_product, expected_product);
+        assert_eq!(result_quotient, expected_quotient);
+    }
+
+    #[test]
+    fn test_location_add_sub() {
+        let first = Point2d::new(3, 4);
+        let second = Point2d::new(5, -1);
+
+        assert_eq!(Location::add(&first, &second), first + second);
+        assert_eq!(Location::sub(&first, &second), first - second);
+    }
+
+    #[test]
+    fn test_from_tuple() {
+        let expected = Point2d::new(3, -4);
+
+        let result = Point2d::from((3, -4));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_display() {
+        let point = Point2d::new(-3, 4);
+
+        let expected = "-3,4";
+
+        let result = point.to_string();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Point2d::new(8, -2);
+
+        let result = "8,-2".parse();
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        let expected = vec![
+            String::from("expected 'x,y'"),
+            String::from("expected a number, but got 'a'"),
+        ];
+
+        let result: Vec<String> = ["8;2", "8,a"]
+            .iter()
+            .map(|input| input.parse::<Point2d<i32>>().unwrap_err().to_string())
+            .collect();
 
         assert_eq!(result, expected);
     }
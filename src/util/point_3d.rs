@@ -1,81 +1,223 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+use std::str::FromStr;
 
 use super::location::Location;
+use super::math::abs_difference;
+use super::parse_error::{parse_number, ParseError};
 
 #[derive(Debug, Eq, PartialEq, PartialOrd, Hash, Copy, Clone, Ord)]
 pub struct Point3d<T>
 where
-    T: Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Div<Output = T>
-        + Ord
-        + Into<f64>
-        + Copy,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
 {
     pub x: T,
     pub y: T,
     pub z: T,
 }
 
+impl<T> Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    pub fn new(x: T, y: T, z: T) -> Point3d<T> {
+        Point3d { x, y, z }
+    }
+}
+
+pub const NUMBER_OF_ORIENTATIONS: usize = 24;
+
 impl<T> Point3d<T>
 where
     T: Add<Output = T>
         + Sub<Output = T>
         + Mul<Output = T>
         + Div<Output = T>
+        + Neg<Output = T>
         + Ord
-        + Into<f64>
         + Copy,
 {
-    pub fn new(x: T, y: T, z: T) -> Point3d<T> {
-        Point3d { x, y, z }
+    pub fn rotate(&self, orientation: usize) -> Point3d<T> {
+        let Point3d { x, y, z } = *self;
+
+        let (facing_x, facing_y, facing_z) = match (orientation % NUMBER_OF_ORIENTATIONS) / 4 {
+            0 => (x, y, z),
+            1 => (-x, -y, z),
+            2 => (y, -x, z),
+            3 => (-y, x, z),
+            4 => (z, y, -x),
+            _ => (-z, y, x),
+        };
+
+        let (up_y, up_z) = match orientation % 4 {
+            0 => (facing_y, facing_z),
+            1 => (-facing_z, facing_y),
+            2 => (-facing_y, -facing_z),
+            _ => (facing_z, -facing_y),
+        };
+
+        Point3d::new(facing_x, up_y, up_z)
+    }
+
+    pub fn get_all_orientations(&self) -> Vec<Point3d<T>> {
+        (0..NUMBER_OF_ORIENTATIONS)
+            .map(|orientation| self.rotate(orientation))
+            .collect()
     }
 }
 
 impl<T> Location for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    type ValueOutput = T;
+
+    fn manhattan_distance_to(&self, other: &Point3d<T>) -> T {
+        abs_difference(self.x, other.x)
+            + abs_difference(self.y, other.y)
+            + abs_difference(self.z, other.z)
+    }
+
+    fn chebyshev_distance_to(&self, other: &Point3d<T>) -> T {
+        abs_difference(self.x, other.x)
+            .max(abs_difference(self.y, other.y))
+            .max(abs_difference(self.z, other.z))
+    }
+
+    fn squared_distance_to(&self, other: &Point3d<T>) -> T {
+        let relative_x = abs_difference(self.x, other.x);
+        let relative_y = abs_difference(self.y, other.y);
+        let relative_z = abs_difference(self.z, other.z);
+
+        relative_x * relative_x + relative_y * relative_y + relative_z * relative_z
+    }
+
+    fn add(&self, other: &Point3d<T>) -> Point3d<T> {
+        *self + *other
+    }
+
+    fn sub(&self, other: &Point3d<T>) -> Point3d<T> {
+        *self - *other
+    }
+}
+
+impl<T> Add for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    type Output = Point3d<T>;
+
+    fn add(self, other: Point3d<T>) -> Point3d<T> {
+        Point3d::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T> AddAssign for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    fn add_assign(&mut self, other: Point3d<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T> Sub for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    type Output = Point3d<T>;
+
+    fn sub(self, other: Point3d<T>) -> Point3d<T> {
+        Point3d::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T> Neg for Point3d<T>
 where
     T: Add<Output = T>
         + Sub<Output = T>
         + Mul<Output = T>
         + Div<Output = T>
+        + Neg<Output = T>
         + Ord
-        + Into<f64>
         + Copy,
 {
-    type ValueOutput = T;
+    type Output = Point3d<T>;
 
-    fn manhattan_distance_to(&self, other: &Point3d<T>) -> T {
-        let relative_x = other.x - self.x;
-        let relative_y = other.y - self.y;
-        let relative_z = other.z - self.z;
+    fn neg(self) -> Point3d<T> {
+        Point3d::new(-self.x, -self.y, -self.z)
+    }
+}
 
-        relative_x + relative_y + relative_z
+impl<T> Mul<T> for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    type Output = Point3d<T>;
+
+    fn mul(self, scalar: T) -> Point3d<T> {
+        Point3d::new(self.x * scalar, self.y * scalar, self.z * scalar)
     }
+}
 
-    fn distance_to(&self, other: &Point3d<T>) -> f64 {
-        let relative_x = other.x - self.x;
-        let relative_y = other.y - self.y;
-        let relative_z = other.z - self.z;
+impl<T> Div<T> for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    type Output = Point3d<T>;
 
-        let temp =
-            (relative_x * relative_x + relative_y * relative_y + relative_z * relative_z).into();
+    fn div(self, scalar: T) -> Point3d<T> {
+        Point3d::new(self.x / scalar, self.y / scalar, self.z / scalar)
+    }
+}
 
-        temp.sqrt()
+impl<T> From<(T, T, T)> for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy,
+{
+    fn from((x, y, z): (T, T, T)) -> Point3d<T> {
+        Point3d::new(x, y, z)
     }
+}
 
-    fn add(&self, other: &Point3d<T>) -> Point3d<T> {
-        let new_x = self.x + other.x;
-        let new_y = self.y + other.y;
-        let new_z = self.z + other.z;
+impl<T> fmt::Display for Point3d<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Ord
+        + Copy
+        + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl<T> FromStr for Point3d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Ord + Copy + FromStr,
+{
+    type Err = ParseError;
 
-        Point3d::new(new_x, new_y, new_z)
+    fn from_str(input: &str) -> Result<Point3d<T>, ParseError> {
+        let values = input
+            .split(',')
+            .map(parse_number)
+            .collect::<Result<Vec<T>, ParseError>>()?;
+
+        match values.as_slice() {
+            [x, y, z] => Ok(Point3d::new(*x, *y, *z)),
+            _ => Err(ParseError::expected(input, "x,y,z")),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     const EPSILON: f64 = 1e-10;
 
@@ -92,6 +234,39 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_manhattan_distance_to_negative_deltas() {
+        let point = Point3d::new(-5, 5, -5);
+
+        let expected = 15;
+
+        let result = point.manhattan_distance_to(&ORIGIN_POINT);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_chebyshev_distance_to() {
+        let point = Point3d::new(2, -9, 4);
+
+        let expected = 9;
+
+        let result = ORIGIN_POINT.chebyshev_distance_to(&point);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_squared_distance_to() {
+        let point = Point3d::new(-3, 4, -5_i64);
+
+        let expected = 50;
+
+        let result = Point3d::new(0, 0, 0).squared_distance_to(&point);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_distance_to() {
         let point = Point3d::new(3, 4, 5);
@@ -110,8 +285,112 @@ mod tests {
 
         let expected = Point3d::new(8, 3, 0);
 
-        let result = first.add(&second);
+        let result = first + second;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sub() {
+        let first = Point3d::new(3, 4, 5);
+        let second = Point3d::new(5, -1, -5);
+
+        let expected = Point3d::new(-2, 5, 10);
+
+        let result = first - second;
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_neg_add_assign() {
+        let mut point = Point3d::new(3, 4, 5);
+
+        let expected = Point3d::new(0, 0, 0);
+
+        point += -point;
+
+        assert_eq!(point, expected);
+    }
+
+    #[test]
+    fn test_scalar_mul_div() {
+        let point = Point3d::new(3, -4, 8);
+
+        let expected_product = Point3d::new(9, -12, 24);
+        let expected_quotient = Point3d::new(1, -1, 2);
+
+        let result_product = point * 3;
+        let result_quotient = point / 3;
+
+        assert_eq!(result_product, expected_product);
+        assert_eq!(result_quotient, expected_quotient);
+    }
+
+    #[test]
+    fn test_from_tuple_display() {
+        let expected = "404,-588,-901";
+
+        let result = Point3d::from((404, -588, -901)).to_string();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = vec![
+            Ok(Point3d::new(404, -588, -901)),
+            Err(String::from("expected 'x,y,z'")),
+            Err(String::from("expected a number, but got 'z'")),
+        ];
+
+        let result: Vec<Result<Point3d<i32>, String>> = ["404,-588,-901", "404,-588", "1,2,z"]
+            .iter()
+            .map(|input| input.parse().map_err(|error: ParseError| error.to_string()))
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rotate_identity() {
+        let point = Point3d::new(1, 2, 3);
+
+        let result = point.rotate(0);
+
+        assert_eq!(result, point);
+    }
+
+    #[test]
+    fn test_get_all_orientations_are_unique() {
+        let point = Point3d::new(1, 2, 3);
+
+        let expected = NUMBER_OF_ORIENTATIONS;
+
+        let result: HashSet<Point3d<i32>> = point.get_all_orientations().into_iter().collect();
+
+        assert_eq!(result.len(), expected);
+    }
+
+    #[test]
+    fn test_get_all_orientations_are_rotations() {
+        let unit_x = Point3d::new(1, 0, 0);
+        let unit_y = Point3d::new(0, 1, 0);
+        let unit_z = Point3d::new(0, 0, 1);
+
+        for orientation in 0..NUMBER_OF_ORIENTATIONS {
+            let rotated_x = unit_x.rotate(orientation);
+            let rotated_y = unit_y.rotate(orientation);
+
+            let expected = unit_z.rotate(orientation);
+
+            let result = Point3d::new(
+                rotated_x.y * rotated_y.z - rotated_x.z * rotated_y.y,
+                rotated_x.z * rotated_y.x - rotated_x.x * rotated_y.z,
+                rotated_x.x * rotated_y.y - rotated_x.y * rotated_y.x,
+            );
+
+            assert_eq!(result, expected);
+        }
+    }
 }
@@ -0,0 +1,361 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+pub fn breadth_first_search<S, N, I, G>(
+    start: S,
+    mut get_neighbors: N,
+    mut is_goal: G,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut states = vec![start.clone()];
+    let mut state_ids = HashMap::from([(start, 0)]);
+    let mut previous_ids: Vec<Option<usize>> = vec![None];
+
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(id) = queue.pop_front() {
+        if is_goal(&states[id]) {
+            let path = get_path(&states, &previous_ids, id);
+
+            return Some((path.len() - 1, path));
+        }
+
+        for neighbor in get_neighbors(&states[id]) {
+            if state_ids.contains_key(&neighbor) {
+                continue;
+            }
+
+            state_ids.insert(neighbor.clone(), states.len());
+            states.push(neighbor);
+            previous_ids.push(Some(id));
+
+            queue.push_back(states.len() - 1);
+        }
+    }
+
+    None
+}
+
+pub fn find_reachable<S, N, I>(start: S, mut get_neighbors: N) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen_states = HashSet::from([start.clone()]);
+    let mut result = vec![start];
+    let mut next_index = 0;
+
+    while let Some(state) = result.get(next_index) {
+        let neighbors: Vec<S> = get_neighbors(state)
+            .into_iter()
+            .filter(|neighbor| seen_states.insert(neighbor.clone()))
+            .collect();
+
+        result.extend(neighbors);
+        next_index += 1;
+    }
+
+    result
+}
+
+pub fn dijkstra<S, C, N, I, G>(start: S, get_neighbors: N, is_goal: G) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    a_star(start, get_neighbors, |_| C::default(), is_goal)
+}
+
+pub fn a_star<S, C, N, I, H, G>(
+    start: S,
+    mut get_neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    let mut states = vec![start.clone()];
+    let mut state_ids = HashMap::from([(start, 0)]);
+    let mut lowest_costs = vec![C::default()];
+    let mut previous_ids: Vec<Option<usize>> = vec![None];
+
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        if lowest_costs[id] < cost {
+            continue;
+        }
+
+        if is_goal(&states[id]) {
+            return Some((cost, get_path(&states, &previous_ids, id)));
+        }
+
+        for (neighbor, step_cost) in get_neighbors(&states[id]) {
+            let neighbor_cost = cost + step_cost;
+
+            let neighbor_id = match state_ids.get(&neighbor) {
+                Some(&neighbor_id) => {
+                    if lowest_costs[neighbor_id] <= neighbor_cost {
+                        continue;
+                    }
+
+                    lowest_costs[neighbor_id] = neighbor_cost;
+                    previous_ids[neighbor_id] = Some(id);
+
+                    neighbor_id
+                }
+                None => {
+                    state_ids.insert(neighbor.clone(), states.len());
+                    states.push(neighbor);
+                    lowest_costs.push(neighbor_cost);
+                    previous_ids.push(Some(id));
+
+                    states.len() - 1
+                }
+            };
+
+            let estimated_cost = neighbor_cost + heuristic(&states[neighbor_id]);
+
+            queue.push(Reverse((estimated_cost, neighbor_cost, neighbor_id)));
+        }
+    }
+
+    None
+}
+
+pub fn count_paths<S, N, I, G>(start: S, mut get_neighbors: N, mut is_goal: G) -> Option<usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut path_counts = HashMap::new();
+    let mut states_in_progress = HashSet::new();
+
+    count_paths_from(
+        &start,
+        &mut get_neighbors,
+        &mut is_goal,
+        &mut path_counts,
+        &mut states_in_progress,
+    )
+}
+
+fn count_paths_from<S, N, I, G>(
+    state: &S,
+    get_neighbors: &mut N,
+    is_goal: &mut G,
+    path_counts: &mut HashMap<S, usize>,
+    states_in_progress: &mut HashSet<S>,
+) -> Option<usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    if is_goal(state) {
+        return Some(1);
+    }
+
+    if let Some(&count) = path_counts.get(state) {
+        return Some(count);
+    }
+
+    if !states_in_progress.insert(state.clone()) {
+        return None;
+    }
+
+    let mut result = 0;
+
+    for neighbor in get_neighbors(state) {
+        result += count_paths_from(
+            &neighbor,
+            get_neighbors,
+            is_goal,
+            path_counts,
+            states_in_progress,
+        )?;
+    }
+
+    states_in_progress.remove(state);
+    path_counts.insert(state.clone(), result);
+
+    Some(result)
+}
+
+fn get_path<S: Clone>(states: &[S], previous_ids: &[Option<usize>], end_id: usize) -> Vec<S> {
+    let mut result = vec![states[end_id].clone()];
+    let mut current_id = end_id;
+
+    while let Some(previous_id) = previous_ids[current_id] {
+        result.push(states[previous_id].clone());
+
+        current_id = previous_id;
+    }
+
+    result.reverse();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::grid::Grid;
+    use crate::util::location::Location;
+    use crate::util::point_2d::Point2d;
+
+    fn get_test_edges() -> HashMap<char, Vec<(char, u32)>> {
+        vec![
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('a', 7), ('c', 10), ('d', 15)]),
+            ('c', vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+            ('d', vec![('b', 15), ('c', 11), ('e', 6)]),
+            ('e', vec![('d', 6), ('f', 9)]),
+            ('f', vec![('a', 14), ('c', 2), ('e', 9)]),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn get_test_maze() -> Grid<bool> {
+        Grid::from_rows(vec![
+            vec![true, true, true, true],
+            vec![false, false, true, false],
+            vec![true, true, true, true],
+            vec![true, false, false, false],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_breadth_first_search() {
+        let edges = get_test_edges();
+
+        let expected = Some((2, vec!['a', 'f', 'e']));
+
+        let result = breadth_first_search(
+            'a',
+            |node| edges[node].iter().map(|&(neighbor, _)| neighbor),
+            |node| *node == 'e',
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_find_reachable() {
+        let maze = get_test_maze();
+
+        let expected = 10;
+
+        let result = find_reachable(Point2d::new(0, 0), |point| {
+            maze.get_neighbors(point)
+                .filter(|neighbor| maze.get(neighbor) == Some(&true))
+                .collect::<Vec<Point2d<i32>>>()
+        });
+
+        assert_eq!(result.len(), expected);
+        assert_eq!(result[0], Point2d::new(0, 0));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = get_test_edges();
+
+        let expected = Some((20, vec!['a', 'c', 'f', 'e']));
+
+        let result = dijkstra('a', |node| edges[node].clone(), |node| *node == 'e');
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable() {
+        let result = dijkstra(0, |_| Vec::<(i32, u32)>::new(), |node| *node == 1);
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_a_star() {
+        let maze = get_test_maze();
+        let goal = Point2d::new(0, 3);
+
+        let expected_cost = 7;
+
+        let result = a_star(
+            Point2d::new(0, 0),
+            |point| {
+                maze.get_neighbors(point)
+                    .filter(|neighbor| maze.get(neighbor) == Some(&true))
+                    .map(|neighbor| (neighbor, 1))
+                    .collect::<Vec<(Point2d<i32>, i32)>>()
+            },
+            |point| point.manhattan_distance_to(&goal),
+            |point| *point == goal,
+        );
+
+        let (result_cost, result_path) = result.unwrap();
+
+        assert_eq!(result_cost, expected_cost);
+        assert_eq!(result_path.len(), expected_cost as usize + 1);
+        assert_eq!(result_path.last(), Some(&goal));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let goal = Point2d::new(2, 2);
+
+        let expected = Some(6);
+
+        let result = count_paths(
+            Point2d::new(0, 0),
+            |point| {
+                [Point2d::new(1, 0), Point2d::new(0, 1)]
+                    .into_iter()
+                    .map(|offset| *point + offset)
+                    .filter(|neighbor| neighbor.x <= goal.x && neighbor.y <= goal.y)
+                    .collect::<Vec<Point2d<i32>>>()
+            },
+            |point| *point == goal,
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_count_paths_cyclic() {
+        let edges: HashMap<char, Vec<char>> = vec![
+            ('a', vec!['b']),
+            ('b', vec!['c', 'd']),
+            ('c', vec!['b']),
+            ('d', vec![]),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = count_paths('a', |node| edges[node].clone(), |node| *node == 'd');
+
+        assert_eq!(result, None);
+    }
+}